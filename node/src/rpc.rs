@@ -10,7 +10,10 @@ use std::sync::Arc;
 use jsonrpsee::RpcModule;
 
 use rmrk_substrate_runtime::{
-	opaque::Block, AccountId, Balance, CollectionSymbolLimit, Index, KeyLimit,
+	opaque::Block,
+	pallet_rmrk_core::types::CollectionRole,
+	pallet_rmrk_market::types::{CollectionStats, SaleRecord},
+	AccountId, Balance, BlockNumber, CollectionSymbolLimit, Index, KeyLimit,
 	MaxCollectionsEquippablePerPart, MaxPropertiesPerTheme, PartsLimit, UniquesStringLimit,
 	ValueLimit,
 };
@@ -66,6 +69,10 @@ where
 			BoundedVec<u8, UniquesStringLimit>,
 			BoundedVec<ThemeProperty<BoundedVec<u8, UniquesStringLimit>>, MaxPropertiesPerTheme>,
 		>,
+		Balance,
+		SaleRecord<AccountId, Balance, BlockNumber, u32>,
+		CollectionStats<Balance, BlockNumber>,
+		CollectionRole,
 	>,
 	P: TransactionPool + 'static,
 {
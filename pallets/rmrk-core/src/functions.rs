@@ -6,18 +6,24 @@
 
 use super::*;
 use codec::{Codec, Decode, Encode};
-use frame_support::traits::{tokens::Locker, Get};
+use frame_support::{
+	traits::{tokens::Locker, Get},
+	weights::Weight,
+};
 
 use sp_runtime::{
-	traits::{Saturating, TrailingZeroInput},
+	traits::{Hash as HashT, Saturating, TrailingZeroInput, Zero},
 	ArithmeticError,
 };
 
-use sp_std::collections::btree_set::BTreeSet;
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
 // Randomness to generate NFT virtual accounts
 pub const SALT_RMRK_NFT: &[u8; 8] = b"RmrkNft/";
 
+// Prefix of the signed payload of permits
+pub const PERMIT_PREFIX: &[u8; 11] = b"RmrkPermit/";
+
 impl<T: Config> Priority<StringLimitOf<T>, T::AccountId, BoundedVec<ResourceId, T::MaxPriorities>>
 	for Pallet<T>
 where
@@ -55,16 +61,7 @@ where
 		key: KeyLimitOf<T>,
 		value: ValueLimitOf<T>,
 	) -> DispatchResult {
-		let collection =
-			Collections::<T>::get(&collection_id).ok_or(Error::<T>::CollectionUnknown)?;
-		//ensure!(collection.issuer == sender, Error::<T>::NoPermission);
-		if let Some(nft_id) = &maybe_nft_id {
-			// Check NFT lock status
-			ensure!(
-				!Pallet::<T>::is_locked(collection_id, *nft_id),
-				pallet_uniques::Error::<T>::Locked
-			);
-		}
+		Self::ensure_property_editable(&sender, collection_id, maybe_nft_id, &key)?;
 		Properties::<T>::insert((&collection_id, maybe_nft_id, &key), &value);
 		Ok(())
 	}
@@ -118,7 +115,13 @@ where
 
 		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
 
-		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+		// Resources added on mint are covered by the minter role
+		let role =
+			if adding_on_mint { CollectionRole::Minter } else { CollectionRole::ResourceManager };
+		ensure!(
+			Self::has_collection_role(&sender, collection_id, &collection, role),
+			Error::<T>::NoPermission
+		);
 		let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
 		// Check NFT lock status
 		ensure!(!Pallet::<T>::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
@@ -195,7 +198,15 @@ where
 	) -> DispatchResult {
 		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
 		let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
-		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+		ensure!(
+			Self::has_collection_role(
+				&sender,
+				collection_id,
+				&collection,
+				CollectionRole::ResourceManager
+			),
+			Error::<T>::NoPermission
+		);
 		ensure!(
 			Resources::<T>::contains_key((collection_id, nft_id, resource_id)),
 			Error::<T>::ResourceDoesntExist
@@ -291,6 +302,22 @@ where
 		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
 		ensure!(collection.nfts_count == 0, Error::<T>::CollectionNotEmpty);
 		Collections::<T>::remove(collection_id);
+		let _ = CollectionRoles::<T>::clear_prefix(
+			(collection_id,),
+			T::MaxCollectionRoles::get(),
+			None,
+		);
+		CollectionRolesCount::<T>::remove(collection_id);
+		PendingCollectionIssuers::<T>::remove(collection_id);
+		FrozenCollectionMetadata::<T>::remove(collection_id);
+		CollectionReveals::<T>::remove(collection_id);
+		// NFT permissions were cleared when the NFTs were burned
+		let _ = PropertyPermissions::<T>::clear_prefix(
+			(collection_id, None::<NftId>),
+			T::MaxPropertyPermissions::get(),
+			None,
+		);
+		PropertyPermissionsCount::<T>::remove((collection_id, None::<NftId>));
 		Ok(())
 	}
 
@@ -306,6 +333,13 @@ where
 			}
 			Ok(())
 		})?;
+		// Roles were granted by the previous issuer
+		let _ = CollectionRoles::<T>::clear_prefix(
+			(collection_id,),
+			T::MaxCollectionRoles::get(),
+			None,
+		);
+		CollectionRolesCount::<T>::remove(collection_id);
 
 		Ok((new_issuer, collection_id))
 	}
@@ -316,7 +350,15 @@ where
 	) -> Result<CollectionId, DispatchError> {
 		Collections::<T>::try_mutate_exists(collection_id, |collection| -> DispatchResult {
 			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
-			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			ensure!(
+				Self::has_collection_role(
+					&sender,
+					collection_id,
+					collection,
+					CollectionRole::Admin
+				),
+				Error::<T>::NoPermission
+			);
 			collection.max = Some(collection.nfts_count);
 			Ok(())
 		})?;
@@ -333,7 +375,7 @@ where
 	fn nft_mint(
 		sender: T::AccountId,
 		owner: T::AccountId,
-		nft_id: NftId,
+		nft_id: Option<NftId>,
 		collection_id: CollectionId,
 		royalty_recipient: Option<T::AccountId>,
 		royalty_amount: Option<Permill>,
@@ -341,6 +383,7 @@ where
 		transferable: bool,
 		resources: Option<BoundedResourceInfoTypeOf<T>>,
 	) -> sp_std::result::Result<(CollectionId, NftId), DispatchError> {
+		let nft_id = Self::nft_id_or_next(collection_id, nft_id)?;
 		ensure!(!Self::nft_exists((collection_id, nft_id)), Error::<T>::NftAlreadyExists);
 		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
 
@@ -416,7 +459,7 @@ where
 	fn nft_mint_directly_to_nft(
 		sender: T::AccountId,
 		owner: (CollectionId, NftId),
-		nft_id: NftId,
+		nft_id: Option<NftId>,
 		collection_id: CollectionId,
 		royalty_recipient: Option<T::AccountId>,
 		royalty_amount: Option<Permill>,
@@ -424,6 +467,7 @@ where
 		transferable: bool,
 		resources: Option<BoundedResourceInfoTypeOf<T>>,
 	) -> sp_std::result::Result<(CollectionId, NftId), DispatchError> {
+		let nft_id = Self::nft_id_or_next(collection_id, nft_id)?;
 		ensure!(!Self::nft_exists((collection_id, nft_id)), Error::<T>::NftAlreadyExists);
 		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
 
@@ -518,6 +562,17 @@ where
 
 		Nfts::<T>::remove(collection_id, nft_id);
 
+		NftUsers::<T>::remove(collection_id, nft_id);
+		FrozenNftMetadata::<T>::remove(collection_id, nft_id);
+		let _ = PropertyPermissions::<T>::clear_prefix(
+			(collection_id, Some(nft_id)),
+			T::MaxPropertyPermissions::get(),
+			None,
+		);
+		PropertyPermissionsCount::<T>::remove((collection_id, Some(nft_id)));
+
+		TransferApprovals::<T>::remove(collection_id, nft_id);
+
 		Resources::<T>::remove_prefix((collection_id, nft_id), None);
 
 		for ((child_collection_id, child_nft_id), _) in
@@ -548,8 +603,12 @@ where
 		ensure!(parent.is_some(), Error::<T>::NoAvailableNftId); // <- is this error wrong?
 
 		let (root_owner, _root_nft) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
-		// Check ownership
-		ensure!(sender == root_owner, Error::<T>::NoPermission);
+		// Check ownership or approval
+		ensure!(
+			sender == root_owner ||
+				Pallet::<T>::is_approved_operator(&sender, &root_owner, collection_id, nft_id),
+			Error::<T>::NoPermission
+		);
 		// Get NFT info
 		let mut sending_nft =
 			Nfts::<T>::get(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
@@ -595,6 +654,9 @@ where
 		sending_nft.owner = new_owner;
 		// Nfts::<T>::insert(collection_id, nft_id, sending_nft);
 
+		// Approvals do not carry over to the new owner
+		TransferApprovals::<T>::remove(collection_id, nft_id);
+
 		if approval_required {
 			Nfts::<T>::try_mutate_exists(collection_id, nft_id, |nft| -> DispatchResult {
 				if let Some(nft) = nft {
@@ -914,6 +976,69 @@ where
 		Ok(())
 	}
 
+	/// Get the next NFT id of a collection and advance the collection's counter past it. The
+	/// counter is kept past every NFT minted with an explicit id, so the id is always free.
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection ID of the NFT to be minted
+	///
+	/// Output:
+	/// - `Result<NftId, Error<T>>`
+	pub fn get_next_nft_id(collection_id: CollectionId) -> Result<NftId, Error<T>> {
+		NextNftId::<T>::try_mutate(collection_id, |next_id| {
+			let nft_id = *next_id;
+			*next_id = nft_id.checked_add(1).ok_or(Error::<T>::NoAvailableNftId)?;
+			Ok(nft_id)
+		})
+	}
+
+	/// Weight of minting one NFT in `mint_batch` and `airdrop`
+	///
+	/// Parameters:
+	/// - `resources`: Number of resources added on mint
+	///
+	/// Output:
+	/// - `Weight`
+	pub fn mint_weight(resources: u32) -> Weight {
+		// NFT id counter, NFT, collection and pallet_uniques mint
+		let mint = 10_000 + T::DbWeight::get().reads_writes(7, 6);
+		// Resource, collection, minter role, root owner and lock reads, resource and equippable
+		// bases and slots writes
+		let resource = T::DbWeight::get().reads_writes(5, 3);
+		mint.saturating_add(resource.saturating_mul(resources as Weight))
+	}
+
+	/// Weight of minting the entries of `mint_batch`, each with its own resources
+	///
+	/// Parameters:
+	/// - `entries`: The NFTs to be minted
+	///
+	/// Output:
+	/// - `Weight`
+	pub fn mint_batch_weight(entries: &[MintEntryOf<T>]) -> Weight {
+		entries.iter().fold(0, |weight: Weight, entry| {
+			let resources = entry.resources.as_ref().map_or(0, |resources| resources.len());
+			weight.saturating_add(Self::mint_weight(resources as u32))
+		})
+	}
+
+	/// Use the NFT id chosen by the minter, advancing the collection's counter past it, or the
+	/// next NFT id of the collection
+	fn nft_id_or_next(
+		collection_id: CollectionId,
+		nft_id: Option<NftId>,
+	) -> Result<NftId, Error<T>> {
+		match nft_id {
+			Some(nft_id) => {
+				// The counter cannot move past the last id
+				let after = nft_id.checked_add(1).ok_or(Error::<T>::NoAvailableNftId)?;
+				NextNftId::<T>::mutate(collection_id, |next_id| *next_id = (*next_id).max(after));
+				Ok(nft_id)
+			},
+			None => Self::get_next_nft_id(collection_id),
+		}
+	}
+
 	/// Helper function for checking if an NFT exists
 	pub fn nft_exists(item: (CollectionId, NftId)) -> bool {
 		let (item_collection_id, item_nft_id) = item;
@@ -925,4 +1050,444 @@ where
 		ensure!(!nft.equipped, Error::<T>::CannotSendEquippedItem);
 		Ok(())
 	}
+
+	/// Grant usage rights on an NFT, the caller is responsible for checking ownership
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection ID of the NFT
+	/// - `nft_id`: NFT ID
+	/// - `user`: Account to be granted the usage rights
+	/// - `expires`: Block at which the usage rights end
+	///
+	/// Output:
+	/// - Inserting the `user` into the NftUsers StorageDoubleMap
+	pub fn user_set(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		user: T::AccountId,
+		expires: T::BlockNumber,
+	) -> DispatchResult {
+		ensure!(Self::nft_exists((collection_id, nft_id)), Error::<T>::NoAvailableNftId);
+		ensure!(!Pallet::<T>::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+		ensure!(expires > <frame_system::Pallet<T>>::block_number(), Error::<T>::InvalidUserExpiry);
+		ensure!(Self::user_of(collection_id, nft_id).is_none(), Error::<T>::UserStillActive);
+
+		NftUsers::<T>::insert(collection_id, nft_id, UserInfo { user: user.clone(), expires });
+
+		Self::deposit_event(Event::UserSet { collection_id, nft_id, user, expires });
+		Ok(())
+	}
+
+	/// Check whether the metadata of a collection, or of one of its NFTs, is frozen. Freezing
+	/// the collection also freezes the metadata of its NFTs
+	pub fn is_metadata_frozen(collection_id: CollectionId, maybe_nft_id: Option<NftId>) -> bool {
+		Self::collection_metadata_frozen(collection_id) ||
+			maybe_nft_id.map_or(false, |nft_id| Self::nft_metadata_frozen(collection_id, nft_id))
+	}
+
+	/// Replace the metadata of an NFT, the caller is responsible for checking it is the issuer
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection ID of the NFT
+	/// - `nft_id`: NFT ID
+	/// - `metadata`: New metadata of the NFT
+	pub fn nft_metadata_set(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		metadata: StringLimitOf<T>,
+	) -> DispatchResult {
+		ensure!(!Self::is_metadata_frozen(collection_id, Some(nft_id)), Error::<T>::MetadataFrozen);
+		ensure!(!Pallet::<T>::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+
+		Nfts::<T>::try_mutate(collection_id, nft_id, |nft| -> DispatchResult {
+			let nft = nft.as_mut().ok_or(Error::<T>::NoAvailableNftId)?;
+			nft.metadata = metadata.clone();
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::NftMetadataSet { collection_id, nft_id, metadata });
+		Ok(())
+	}
+
+	/// Replace the metadata of a collection, the caller is responsible for checking it is the
+	/// issuer
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection ID
+	/// - `metadata`: New metadata of the collection
+	pub fn collection_metadata_set(
+		collection_id: CollectionId,
+		metadata: StringLimitOf<T>,
+	) -> DispatchResult {
+		ensure!(!Self::is_metadata_frozen(collection_id, None), Error::<T>::MetadataFrozen);
+
+		Collections::<T>::try_mutate(collection_id, |collection| -> DispatchResult {
+			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
+			collection.metadata = metadata.clone();
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::CollectionMetadataSet { collection_id, metadata });
+		Ok(())
+	}
+
+	/// Set up the delayed reveal of a collection, the caller is responsible for checking it is
+	/// the issuer
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection ID
+	/// - `source`: Base URI, or hash of the base URI
+	/// - `reveal_at`: Optional block from which any account can trigger the reveal
+	pub fn reveal_set(
+		collection_id: CollectionId,
+		source: RevealSourceOf<T>,
+		reveal_at: Option<T::BlockNumber>,
+	) -> DispatchResult {
+		ensure!(!Self::is_metadata_frozen(collection_id, None), Error::<T>::MetadataFrozen);
+		ensure!(
+			Self::collection_reveal(collection_id)
+				.map_or(true, |reveal| reveal.state == RevealState::Pending),
+			Error::<T>::CollectionAlreadyRevealed
+		);
+		if let Some(reveal_at) = reveal_at {
+			ensure!(
+				reveal_at > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidRevealBlock
+			);
+		}
+		if let RevealSource::BaseUri(base_uri) = &source {
+			Self::revealed_metadata(base_uri, NftId::MAX)?;
+		}
+
+		CollectionReveals::<T>::insert(
+			collection_id,
+			RevealInfo { source, reveal_at, state: RevealState::Pending },
+		);
+
+		Self::deposit_event(Event::RevealSet { collection_id, reveal_at });
+		Ok(())
+	}
+
+	/// Reveal the metadata of a collection
+	///
+	/// Parameters:
+	/// - `sender`: The issuer, or any account once the scheduled reveal block is reached
+	/// - `collection_id`: Collection ID
+	/// - `base_uri`: The base URI matching the committed hash, if any
+	/// - `witness_nfts`: Upper bound of the number of NFTs in the collection
+	pub fn collection_reveal_apply(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		base_uri: Option<StringLimitOf<T>>,
+		witness_nfts: u32,
+	) -> DispatchResult {
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		let mut reveal =
+			Self::collection_reveal(collection_id).ok_or(Error::<T>::NoRevealPending)?;
+		ensure!(reveal.state == RevealState::Pending, Error::<T>::CollectionAlreadyRevealed);
+		if collection.issuer != sender {
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				reveal.reveal_at.map_or(false, |reveal_at| now >= reveal_at),
+				Error::<T>::RevealNotDue
+			);
+		}
+		ensure!(!Self::is_metadata_frozen(collection_id, None), Error::<T>::MetadataFrozen);
+		ensure!(collection.nfts_count <= witness_nfts, Error::<T>::InvalidRevealWitness);
+
+		let base_uri = match reveal.source {
+			RevealSource::BaseUri(base_uri) => base_uri,
+			RevealSource::Committed(hash) => {
+				let base_uri = base_uri.ok_or(Error::<T>::InvalidRevealUri)?;
+				ensure!(T::Hashing::hash(&base_uri) == hash, Error::<T>::InvalidRevealUri);
+				base_uri
+			},
+		};
+
+		let nft_ids: Vec<NftId> = Nfts::<T>::iter_key_prefix(collection_id).collect();
+		for nft_id in nft_ids {
+			// Like `nft_metadata_set`, leave frozen and locked NFTs untouched
+			if Self::nft_metadata_frozen(collection_id, nft_id) ||
+				Pallet::<T>::is_locked(collection_id, nft_id)
+			{
+				continue
+			}
+			let metadata = Self::revealed_metadata(&base_uri, nft_id)?;
+			Nfts::<T>::mutate(collection_id, nft_id, |nft| {
+				if let Some(nft) = nft {
+					nft.metadata = metadata;
+				}
+			});
+		}
+
+		reveal.source = RevealSource::BaseUri(base_uri.clone());
+		reveal.state = RevealState::Revealed;
+		CollectionReveals::<T>::insert(collection_id, reveal);
+
+		Self::deposit_event(Event::CollectionRevealed { collection_id, base_uri });
+		Ok(())
+	}
+
+	/// Revealed metadata of an NFT, the base URI followed by the decimal NFT id
+	fn revealed_metadata(
+		base_uri: &StringLimitOf<T>,
+		nft_id: NftId,
+	) -> Result<StringLimitOf<T>, Error<T>> {
+		let mut digits = Vec::new();
+		let mut remaining = nft_id;
+		loop {
+			digits.push(b'0' + (remaining % 10) as u8);
+			remaining /= 10;
+			if remaining == 0 {
+				break
+			}
+		}
+		let mut metadata = base_uri.to_vec();
+		metadata.extend(digits.iter().rev());
+		metadata.try_into().map_err(|_| Error::<T>::RevealUriTooLong)
+	}
+
+	/// Get the account currently allowed to use an NFT, if its rights have not expired
+	pub fn user_of(collection_id: CollectionId, nft_id: NftId) -> Option<T::AccountId> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		NftUsers::<T>::get(collection_id, nft_id)
+			.filter(|info| info.expires > now)
+			.map(|info| info.user)
+	}
+
+	/// Send an NFT and transfer it in pallet_uniques, the checks are done by `nft_send`
+	///
+	/// Parameters:
+	/// - `sender`: Root owner of the NFT, or an approved operator
+	/// - `collection_id`: Collection ID of the NFT
+	/// - `nft_id`: NFT ID
+	/// - `new_owner`: Either an account or an NFT
+	pub fn nft_transfer(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		new_owner: AccountIdOrCollectionNftTuple<T::AccountId>,
+	) -> DispatchResult {
+		let (new_owner_account, approval_required) =
+			Self::nft_send(sender.clone(), collection_id, nft_id, new_owner.clone())?;
+
+		pallet_uniques::Pallet::<T>::do_transfer(
+			collection_id,
+			nft_id,
+			new_owner_account,
+			|_class_details, _details| Ok(()),
+		)?;
+
+		Self::deposit_event(Event::NFTSent {
+			sender,
+			recipient: new_owner,
+			collection_id,
+			nft_id,
+			approval_required,
+		});
+		Ok(())
+	}
+
+	/// Approve an operator to send an NFT on behalf of its root owner
+	///
+	/// Parameters:
+	/// - `sender`: Root owner of the NFT
+	/// - `collection_id`: Collection ID of the NFT
+	/// - `nft_id`: NFT ID
+	/// - `operator`: Account allowed to send the NFT
+	/// - `expires`: Optional block at which the approval ends
+	pub fn transfer_approve(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		operator: T::AccountId,
+		expires: Option<T::BlockNumber>,
+	) -> DispatchResult {
+		let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+		ensure!(sender == root_owner, Error::<T>::NoPermission);
+		if let Some(expires) = expires {
+			ensure!(
+				expires > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidApprovalExpiry
+			);
+		}
+
+		TransferApprovals::<T>::insert(
+			collection_id,
+			nft_id,
+			TransferApproval { owner: sender.clone(), operator: operator.clone(), expires },
+		);
+
+		Self::deposit_event(Event::TransferApproved {
+			owner: sender,
+			operator,
+			collection_id,
+			nft_id,
+			expires,
+		});
+		Ok(())
+	}
+
+	/// Check the deadline, the nonce and the signature of a permit, and use up its nonce
+	///
+	/// Parameters:
+	/// - `permit`: The permit to check
+	/// - `signature`: Signature of the permit by its signer
+	pub fn permit_verify(permit: &PermitOf<T>, signature: &T::OffchainSignature) -> DispatchResult {
+		ensure!(
+			permit.deadline >= <frame_system::Pallet<T>>::block_number(),
+			Error::<T>::PermitExpired
+		);
+		let nonce = PermitNonces::<T>::get(&permit.signer);
+		ensure!(permit.nonce == nonce, Error::<T>::InvalidPermitNonce);
+
+		// Bind the signature to this chain so that it cannot be replayed on another one
+		let genesis_hash = <frame_system::Pallet<T>>::block_hash(T::BlockNumber::zero());
+		let payload = (PERMIT_PREFIX, genesis_hash, permit).encode();
+		ensure!(signature.verify(&payload[..], &permit.signer), Error::<T>::InvalidPermitSignature);
+
+		PermitNonces::<T>::insert(&permit.signer, nonce.saturating_add(1));
+		Ok(())
+	}
+
+	/// Check whether an account is approved to send an NFT on behalf of its root owner, either
+	/// for this NFT only or for all NFTs of the collection owned by the root owner
+	///
+	/// Parameters:
+	/// - `operator`: Account to check
+	/// - `owner`: Root owner of the NFT
+	/// - `collection_id`: Collection ID of the NFT
+	/// - `nft_id`: NFT ID
+	pub fn is_approved_operator(
+		operator: &T::AccountId,
+		owner: &T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> bool {
+		if OperatorApprovals::<T>::contains_key((owner, collection_id, operator)) {
+			return true
+		}
+		let now = <frame_system::Pallet<T>>::block_number();
+		TransferApprovals::<T>::get(collection_id, nft_id).map_or(false, |approval| {
+			&approval.owner == owner &&
+				&approval.operator == operator &&
+				approval.expires.map_or(true, |expires| expires > now)
+		})
+	}
+
+	/// Check whether an account holds a role on a collection, the issuer holds every role and
+	/// admins hold every other role
+	///
+	/// Parameters:
+	/// - `who`: Account to check
+	/// - `collection_id`: Collection ID
+	/// - `collection`: Info of the collection
+	/// - `role`: Role required
+	pub fn has_collection_role(
+		who: &T::AccountId,
+		collection_id: CollectionId,
+		collection: &CollectionInfoOf<T>,
+		role: CollectionRole,
+	) -> bool {
+		&collection.issuer == who ||
+			CollectionRoles::<T>::contains_key((collection_id, who, CollectionRole::Admin)) ||
+			CollectionRoles::<T>::contains_key((collection_id, who, role))
+	}
+
+	/// Ensure that a collection exists and that an account holds a role on it
+	///
+	/// Parameters:
+	/// - `who`: Account to check
+	/// - `collection_id`: Collection ID
+	/// - `role`: Role required
+	pub fn ensure_collection_role(
+		who: &T::AccountId,
+		collection_id: CollectionId,
+		role: CollectionRole,
+	) -> DispatchResult {
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		ensure!(
+			Self::has_collection_role(who, collection_id, &collection, role),
+			Error::<T>::NoPermission
+		);
+		Ok(())
+	}
+
+	/// Ensure that an account may set or remove a property, according to its mutability
+	///
+	/// Parameters:
+	/// - `sender`: Account editing the property
+	/// - `collection_id`: Collection ID
+	/// - `maybe_nft_id`: NFT ID, or `None` for a collection property
+	/// - `key`: Key of the property
+	pub fn ensure_property_editable(
+		sender: &T::AccountId,
+		collection_id: CollectionId,
+		maybe_nft_id: Option<NftId>,
+		key: &KeyLimitOf<T>,
+	) -> DispatchResult {
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		let allowed = match Self::property_mutability((collection_id, maybe_nft_id, key)) {
+			PropertyMutability::Issuer => Self::has_collection_role(
+				sender,
+				collection_id,
+				&collection,
+				CollectionRole::PropertyEditor,
+			),
+			PropertyMutability::Owner => match maybe_nft_id {
+				Some(nft_id) => &Self::lookup_root_owner(collection_id, nft_id)?.0 == sender,
+				None => &collection.issuer == sender,
+			},
+			PropertyMutability::Immutable => return Err(Error::<T>::PropertyImmutable.into()),
+		};
+		ensure!(allowed, Error::<T>::NoPermission);
+		if let Some(nft_id) = maybe_nft_id {
+			// Check NFT lock status
+			ensure!(
+				!Pallet::<T>::is_locked(collection_id, nft_id),
+				pallet_uniques::Error::<T>::Locked
+			);
+		}
+		Ok(())
+	}
+
+	/// List the roles granted on a collection, the issuer is not listed
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection ID
+	///
+	/// Output:
+	/// - `Vec<(T::AccountId, CollectionRole)>`
+	pub fn collection_role_holders(
+		collection_id: CollectionId,
+	) -> Vec<(T::AccountId, CollectionRole)> {
+		CollectionRoles::<T>::iter_prefix((collection_id,))
+			.map(|((account, role), _)| (account, role))
+			.collect()
+	}
+
+	/// Check whether an account may use an NFT, either as its root owner or as the active user
+	/// of the NFT or of the NFT at the root of its tree
+	///
+	/// Parameters:
+	/// - `who`: Account to check
+	/// - `collection_id`: Collection ID of the NFT
+	/// - `nft_id`: NFT ID
+	///
+	/// Output:
+	/// - `Result<bool, Error<T>>`
+	pub fn is_owner_or_user(
+		who: &T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> Result<bool, Error<T>> {
+		let (root_owner, (root_collection_id, root_nft_id)) =
+			Self::lookup_root_owner(collection_id, nft_id)?;
+		let is_user = |collection_id, nft_id| {
+			Self::user_of(collection_id, nft_id).map_or(false, |user| &user == who)
+		};
+		Ok(&root_owner == who ||
+			is_user(collection_id, nft_id) ||
+			is_user(root_collection_id, root_nft_id))
+	}
 }
@@ -11,7 +11,10 @@ use frame_support::{
 };
 use frame_system::ensure_signed;
 
-use sp_runtime::{traits::StaticLookup, DispatchError, Permill};
+use sp_runtime::{
+	traits::{IdentifyAccount, StaticLookup, Verify},
+	DispatchError, Permill,
+};
 use sp_std::convert::TryInto;
 
 use rmrk_traits::{
@@ -22,6 +25,7 @@ use rmrk_traits::{
 use sp_std::result::Result;
 
 mod functions;
+pub mod migration;
 
 #[cfg(test)]
 mod mock;
@@ -73,8 +77,38 @@ pub type BoundedResourceInfoTypeOf<T> = BoundedVec<
 
 pub type PropertyInfoOf<T> = PropertyInfo<KeyLimitOf<T>, ValueLimitOf<T>>;
 
+pub type UserInfoOf<T> =
+	UserInfo<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+
+pub type TransferApprovalOf<T> = TransferApproval<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+pub type PermitOf<T> =
+	Permit<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+
+pub type MintEntryOf<T> = MintEntry<
+	<T as frame_system::Config>::AccountId,
+	StringLimitOf<T>,
+	BoundedResourceInfoTypeOf<T>,
+>;
+
+pub type RevealSourceOf<T> = RevealSource<StringLimitOf<T>, <T as frame_system::Config>::Hash>;
+
+pub type RevealInfoOf<T> = RevealInfo<
+	StringLimitOf<T>,
+	<T as frame_system::Config>::Hash,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
 pub mod types;
 
+use types::{
+	CollectionRole, MintEntry, Permit, PermitAction, PropertyMutability, RevealInfo, RevealSource,
+	RevealState, TransferApproval, UserInfo,
+};
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -107,8 +141,31 @@ pub mod pallet {
 		type CollectionSymbolLimit: Get<u32>;
 
 		type MaxResourcesOnMint: Get<u32>;
+
+		/// The maximum number of NFTs that can be minted in a single batch or airdrop
+		#[pallet::constant]
+		type MaxBatchMint: Get<u32>;
+
+		/// The maximum number of roles that can be granted on a collection
+		#[pallet::constant]
+		type MaxCollectionRoles: Get<u32>;
+
+		/// The maximum number of property permissions that can be set on a collection or on an NFT
+		#[pallet::constant]
+		type MaxPropertyPermissions: Get<u32>;
+
+		/// Off-chain signature type used to sign permits
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Public key of the off-chain signature, identifying the account that signed a permit
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
 	}
 
+	#[pallet::storage]
+	#[pallet::getter(fn next_nft_id)]
+	/// Get next NFT id
+	pub type NextNftId<T: Config> = StorageMap<_, Twox64Concat, CollectionId, NftId, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn collection_index)]
 	pub type CollectionIndex<T: Config> = StorageValue<_, CollectionId, ValueQuery>;
@@ -216,11 +273,122 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn property_mutability)]
+	/// Who can set or remove each property, issuer-mutable by default
+	pub type PropertyPermissions<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, Option<NftId>>,
+			NMapKey<Blake2_128Concat, KeyLimitOf<T>>,
+		),
+		PropertyMutability,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn property_permissions_count)]
+	/// Number of property permissions set on a collection or on an NFT, bounded by
+	/// `MaxPropertyPermissions`
+	pub type PropertyPermissionsCount<T: Config> =
+		StorageMap<_, Twox64Concat, (CollectionId, Option<NftId>), u32, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn lock)]
 	/// Lock for NFTs
 	pub type Lock<T: Config> = StorageMap<_, Twox64Concat, (CollectionId, NftId), bool, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn nft_users)]
+	/// Accounts granted usage rights on an NFT, and the block those rights expire at
+	pub type NftUsers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CollectionId,
+		Twox64Concat,
+		NftId,
+		UserInfoOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_approvals)]
+	/// Operator approved to send an NFT on behalf of its root owner
+	pub type TransferApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CollectionId,
+		Twox64Concat,
+		NftId,
+		TransferApprovalOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn permit_nonce)]
+	/// Nonce of the next permit an account can sign
+	pub type PermitNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn operator_approvals)]
+	/// Operators approved by an account to send all of its NFTs of a collection
+	pub type OperatorApprovals<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Twox64Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_role)]
+	/// Roles granted by the issuer of a collection to other accounts
+	pub type CollectionRoles<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Twox64Concat, CollectionRole>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_roles_count)]
+	/// Number of roles granted on a collection, bounded by `MaxCollectionRoles`
+	pub type CollectionRolesCount<T: Config> =
+		StorageMap<_, Twox64Concat, CollectionId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_collection_issuer)]
+	/// Account nominated by the issuer of a collection to become its new issuer
+	pub type PendingCollectionIssuers<T: Config> =
+		StorageMap<_, Twox64Concat, CollectionId, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_metadata_frozen)]
+	/// Collections whose metadata, and the metadata of their NFTs, can no longer change
+	pub type FrozenCollectionMetadata<T: Config> =
+		StorageMap<_, Twox64Concat, CollectionId, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn nft_metadata_frozen)]
+	/// NFTs whose metadata can no longer change
+	pub type FrozenNftMetadata<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CollectionId, Twox64Concat, NftId, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_reveal)]
+	/// Delayed reveal of the metadata of a collection
+	pub type CollectionReveals<T: Config> =
+		StorageMap<_, Twox64Concat, CollectionId, RevealInfoOf<T>, OptionQuery>;
+
 	/// This storage is not used by the chain.
 	/// It is need only for PolkadotJS types generation.
 	///
@@ -230,10 +398,24 @@ pub mod pallet {
 	pub type DummyStorage<T: Config> =
 		StorageValue<_, (NftChild, PhantomType<PropertyInfoOf<T>>), OptionQuery>;
 
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
+	where
+		T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			migration::migrate_to_v1::<T>()
+		}
+	}
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/v3/runtime/events-and-errors
 	#[pallet::event]
@@ -275,6 +457,15 @@ pub mod pallet {
 			collection_id: CollectionId,
 			nft_id: NftId,
 		},
+		IssuerChangeProposed {
+			issuer: T::AccountId,
+			new_issuer: T::AccountId,
+			collection_id: CollectionId,
+		},
+		IssuerChangeCancelled {
+			issuer: T::AccountId,
+			collection_id: CollectionId,
+		},
 		IssuerChanged {
 			old_issuer: T::AccountId,
 			new_issuer: T::AccountId,
@@ -291,6 +482,12 @@ pub mod pallet {
 			maybe_nft_id: Option<NftId>,
 			key: KeyLimitOf<T>,
 		},
+		PropertyMutabilitySet {
+			collection_id: CollectionId,
+			maybe_nft_id: Option<NftId>,
+			key: KeyLimitOf<T>,
+			mutability: PropertyMutability,
+		},
 		CollectionLocked {
 			issuer: T::AccountId,
 			collection_id: CollectionId,
@@ -315,6 +512,70 @@ pub mod pallet {
 			collection_id: CollectionId,
 			nft_id: NftId,
 		},
+		UserSet {
+			collection_id: CollectionId,
+			nft_id: NftId,
+			user: T::AccountId,
+			expires: T::BlockNumber,
+		},
+		TransferApproved {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			expires: Option<T::BlockNumber>,
+		},
+		ApprovalCancelled {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		},
+		ApprovalForAll {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			collection_id: CollectionId,
+			approved: bool,
+		},
+		PermitUsed {
+			signer: T::AccountId,
+			relayer: T::AccountId,
+			nonce: u64,
+		},
+		NftMetadataSet {
+			collection_id: CollectionId,
+			nft_id: NftId,
+			metadata: StringLimitOf<T>,
+		},
+		CollectionMetadataSet {
+			collection_id: CollectionId,
+			metadata: StringLimitOf<T>,
+		},
+		NftMetadataFrozen {
+			collection_id: CollectionId,
+			nft_id: NftId,
+		},
+		CollectionMetadataFrozen {
+			collection_id: CollectionId,
+		},
+		RevealSet {
+			collection_id: CollectionId,
+			reveal_at: Option<T::BlockNumber>,
+		},
+		CollectionRevealed {
+			collection_id: CollectionId,
+			base_uri: StringLimitOf<T>,
+		},
+		CollectionRoleGranted {
+			collection_id: CollectionId,
+			account: T::AccountId,
+			role: CollectionRole,
+		},
+		CollectionRoleRevoked {
+			collection_id: CollectionId,
+			account: T::AccountId,
+			role: CollectionRole,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -353,6 +614,48 @@ pub mod pallet {
 		// Must unequip an item before sending (this only applies to the
 		// rmrk-equip pallet but the send operation lives in rmrk-core)
 		CannotSendEquippedItem,
+		/// The NFT already has a user whose rights have not expired yet
+		UserStillActive,
+		/// The usage rights must expire after the current block
+		InvalidUserExpiry,
+		/// The NFT has no transfer approval
+		ApprovalDoesntExist,
+		/// The transfer approval must expire after the current block
+		InvalidApprovalExpiry,
+		/// The deadline of the permit has passed
+		PermitExpired,
+		/// The permit nonce is not the next nonce of the signer
+		InvalidPermitNonce,
+		/// The permit is not signed by its signer
+		InvalidPermitSignature,
+		/// The account does not hold this role on the collection
+		CollectionRoleNotGranted,
+		/// The collection already has `MaxCollectionRoles` roles granted
+		TooManyCollectionRoles,
+		/// The collection or NFT already has `MaxPropertyPermissions` property permissions set
+		TooManyPropertyPermissions,
+		/// No new issuer is nominated for the collection
+		NoIssuerChangePending,
+		/// The metadata of the NFT or of its collection is frozen
+		MetadataFrozen,
+		/// No reveal is set for the collection
+		NoRevealPending,
+		/// The collection is already revealed
+		CollectionAlreadyRevealed,
+		/// The scheduled reveal block is not reached yet
+		RevealNotDue,
+		/// The scheduled reveal block must be in the future
+		InvalidRevealBlock,
+		/// The base URI is missing or does not match the committed hash
+		InvalidRevealUri,
+		/// The base URI followed by an NFT id exceeds the metadata limit
+		RevealUriTooLong,
+		/// The witness is lower than the number of NFTs in the collection
+		InvalidRevealWitness,
+		/// The property can no longer change
+		PropertyImmutable,
+		/// The property does not exist
+		PropertyDoesntExist,
 	}
 
 	#[pallet::call]
@@ -365,7 +668,8 @@ pub mod pallet {
 		///
 		/// Parameters:
 		/// - `collection_id`: The collection of the asset to be minted.
-		/// - `nft_id`: The nft value of the asset to be minted.
+		/// - `nft_id`: The nft value of the asset to be minted, the next free id of the collection
+		///   if `None`. The minted id is reported in the `NftMinted` event.
 		/// - `recipient`: Receiver of the royalty
 		/// - `royalty`: Permillage reward from each trade for the Recipient
 		/// - `metadata`: Arbitrary data about an nft, e.g. IPFS hash
@@ -374,7 +678,7 @@ pub mod pallet {
 		pub fn mint_nft(
 			origin: OriginFor<T>,
 			owner: Option<T::AccountId>,
-			nft_id: NftId,
+			nft_id: Option<NftId>,
 			collection_id: CollectionId,
 			royalty_recipient: Option<T::AccountId>,
 			royalty: Option<Permill>,
@@ -383,13 +687,7 @@ pub mod pallet {
 			resources: Option<BoundedResourceInfoTypeOf<T>>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			if let Some(collection_issuer) =
-				pallet_uniques::Pallet::<T>::collection_owner(collection_id)
-			{
-				ensure!(collection_issuer == sender, Error::<T>::NoPermission);
-			} else {
-				return Err(Error::<T>::CollectionUnknown.into())
-			}
+			Self::ensure_collection_role(&sender, collection_id, CollectionRole::Minter)?;
 
 			// Extract intended owner or default to sender
 			let nft_owner = match owner {
@@ -418,7 +716,8 @@ pub mod pallet {
 		///
 		/// Parameters:
 		/// - `collection_id`: The class of the asset to be minted.
-		/// - `nft_id`: The nft value of the asset to be minted.
+		/// - `nft_id`: The nft value of the asset to be minted, the next free id of the collection
+		///   if `None`. The minted id is reported in the `NftMinted` event.
 		/// - `recipient`: Receiver of the royalty
 		/// - `royalty`: Permillage reward from each trade for the Recipient
 		/// - `metadata`: Arbitrary data about an nft, e.g. IPFS hash
@@ -427,7 +726,7 @@ pub mod pallet {
 		pub fn mint_nft_directly_to_nft(
 			origin: OriginFor<T>,
 			owner: (CollectionId, NftId),
-			nft_id: NftId,
+			nft_id: Option<NftId>,
 			collection_id: CollectionId,
 			royalty_recipient: Option<T::AccountId>,
 			royalty: Option<Permill>,
@@ -437,14 +736,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin.clone())?;
 
-			// Collection must exist and sender must be issuer of collection
-			if let Some(collection_issuer) =
-				pallet_uniques::Pallet::<T>::collection_owner(collection_id)
-			{
-				ensure!(collection_issuer == sender, Error::<T>::NoPermission);
-			} else {
-				return Err(Error::<T>::CollectionUnknown.into())
-			}
+			// Collection must exist and sender must be allowed to mint in the collection
+			Self::ensure_collection_role(&sender, collection_id, CollectionRole::Minter)?;
 
 			// Mint NFT for RMRK storage
 			Self::nft_mint_directly_to_nft(
@@ -462,6 +755,88 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Mints a batch of NFTs in the specified collection, each to the next free id
+		/// The batch is all-or-nothing, a single failing entry reverts every mint
+		///
+		/// Parameters:
+		/// - `collection_id`: The collection of the assets to be minted.
+		/// - `entries`: Owner, royalty, metadata, transferability and resources of each NFT
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads(2)
+				+ Pallet::<T>::mint_batch_weight(entries)
+		)]
+		#[transactional]
+		pub fn mint_batch(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			entries: BoundedVec<MintEntryOf<T>, T::MaxBatchMint>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_collection_role(&sender, collection_id, CollectionRole::Minter)?;
+
+			for entry in entries {
+				Self::nft_mint(
+					sender.clone(),
+					entry.owner.unwrap_or_else(|| sender.clone()),
+					None,
+					collection_id,
+					entry.royalty_recipient,
+					entry.royalty,
+					entry.metadata,
+					entry.transferable,
+					entry.resources,
+				)?;
+			}
+
+			Ok(())
+		}
+
+		/// Mints one NFT from the same template to each recipient, at the next free ids of the
+		/// collection. NFTs minted to another account than the issuer are pending until accepted
+		/// The airdrop is all-or-nothing, a single failing mint reverts every mint
+		///
+		/// Parameters:
+		/// - `collection_id`: The collection of the assets to be minted.
+		/// - `recipients`: The owners of the minted NFTs
+		/// - `royalty_recipient`: Receiver of the royalty
+		/// - `royalty`: Permillage reward from each trade for the Recipient
+		/// - `metadata`: Arbitrary data about an nft, e.g. IPFS hash
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads(2)
+				+ Pallet::<T>::mint_weight(resources.as_ref().map_or(0, |r| r.len() as u32))
+					.saturating_mul(recipients.len() as u64)
+		)]
+		#[transactional]
+		pub fn airdrop(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			recipients: BoundedVec<T::AccountId, T::MaxBatchMint>,
+			royalty_recipient: Option<T::AccountId>,
+			royalty: Option<Permill>,
+			metadata: BoundedVec<u8, T::StringLimit>,
+			transferable: bool,
+			resources: Option<BoundedResourceInfoTypeOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_collection_role(&sender, collection_id, CollectionRole::Minter)?;
+
+			for recipient in recipients {
+				Self::nft_mint(
+					sender.clone(),
+					recipient,
+					None,
+					collection_id,
+					royalty_recipient.clone(),
+					royalty,
+					metadata.clone(),
+					transferable,
+					resources.clone(),
+				)?;
+			}
+
+			Ok(())
+		}
+
 		/// Create a collection
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
@@ -478,8 +853,14 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// burn nft
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		/// burn nft, clearing up to `MaxPropertyPermissions` property permissions of each burned
+		/// NFT
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 2)
+				+ T::DbWeight::get()
+					.writes(T::MaxPropertyPermissions::get() as u64)
+					.saturating_mul(*max_burns as u64)
+		)]
 		#[transactional]
 		pub fn burn_nft(
 			origin: OriginFor<T>,
@@ -500,7 +881,11 @@ pub mod pallet {
 		}
 
 		/// destroy collection
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 3)
+				+ T::DbWeight::get().writes(T::MaxCollectionRoles::get() as u64)
+				+ T::DbWeight::get().writes(T::MaxPropertyPermissions::get() as u64)
+		)]
 		#[transactional]
 		pub fn destroy_collection(
 			origin: OriginFor<T>,
@@ -541,25 +926,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			let (new_owner_account, approval_required) =
-				Self::nft_send(sender.clone(), collection_id, nft_id, new_owner.clone())?;
-
-			pallet_uniques::Pallet::<T>::do_transfer(
-				collection_id,
-				nft_id,
-				new_owner_account,
-				|_class_details, _details| Ok(()),
-			)?;
-
-			Self::deposit_event(Event::NFTSent {
-				sender,
-				recipient: new_owner.clone(),
-				collection_id,
-				nft_id,
-				approval_required,
-			});
-
-			Ok(())
+			Self::nft_transfer(sender, collection_id, nft_id, new_owner)
 		}
 		/// Accepts an NFT sent from another account to self or owned NFT
 		///
@@ -604,7 +971,12 @@ pub mod pallet {
 		/// - `origin`: sender of the transaction
 		/// - `collection_id`: collection id of the nft to be accepted
 		/// - `nft_id`: nft id of the nft to be accepted
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 2)
+				+ T::DbWeight::get()
+					.writes(T::MaxPropertyPermissions::get() as u64)
+					.saturating_mul(T::MaxRecursions::get() as u64)
+		)]
 		#[transactional]
 		pub fn reject_nft(
 			origin: OriginFor<T>,
@@ -621,7 +993,8 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Change the issuer of a collection
+		/// Nominate a new issuer for a collection, the issuer only changes once the nominee
+		/// accepts with `accept_collection_issuer`. A new nomination replaces the pending one
 		///
 		/// Parameters:
 		/// - `origin`: sender of the transaction
@@ -634,30 +1007,225 @@ pub mod pallet {
 			collection_id: CollectionId,
 			new_issuer: <T::Lookup as StaticLookup>::Source,
 		) -> DispatchResult {
-			let sender = ensure_signed(origin.clone())?;
+			let sender = ensure_signed(origin)?;
 			let collection =
 				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
 			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
-			let new_owner = T::Lookup::lookup(new_issuer.clone())?;
+			let new_issuer = T::Lookup::lookup(new_issuer)?;
 
-			ensure!(
-				Collections::<T>::contains_key(collection_id),
-				Error::<T>::NoAvailableCollectionId
-			);
+			PendingCollectionIssuers::<T>::insert(collection_id, &new_issuer);
 
-			let (new_owner, collection_id) =
-				Self::collection_change_issuer(collection_id, new_owner)?;
+			Self::deposit_event(Event::IssuerChangeProposed {
+				issuer: sender,
+				new_issuer,
+				collection_id,
+			});
+			Ok(())
+		}
 
-			pallet_uniques::Pallet::<T>::transfer_ownership(origin, collection_id, new_issuer)?;
+		/// Accept the nomination as new issuer of a collection, the roles granted by the
+		/// previous issuer are revoked, up to `MaxCollectionRoles` of them
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, the nominated issuer
+		/// - `collection_id`: collection id of the nft to become the issuer of
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(5, 7)
+				+ T::DbWeight::get().writes(T::MaxCollectionRoles::get() as u64)
+		)]
+		#[transactional]
+		pub fn accept_collection_issuer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin.clone())?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			let nominee = PendingCollectionIssuers::<T>::take(collection_id)
+				.ok_or(Error::<T>::NoIssuerChangePending)?;
+			ensure!(nominee == sender, Error::<T>::NoPermission);
+
+			let (new_owner, collection_id) = Self::collection_change_issuer(collection_id, sender)?;
+
+			// Accepting the nomination also accepts the ownership of the uniques collection
+			pallet_uniques::Pallet::<T>::set_accept_ownership(origin, Some(collection_id))?;
+			pallet_uniques::Pallet::<T>::transfer_ownership(
+				frame_system::RawOrigin::Signed(collection.issuer.clone()).into(),
+				collection_id,
+				T::Lookup::unlookup(new_owner.clone()),
+			)?;
 
 			Self::deposit_event(Event::IssuerChanged {
-				old_issuer: sender,
+				old_issuer: collection.issuer,
 				new_issuer: new_owner,
 				collection_id,
 			});
 			Ok(())
 		}
 
+		/// Cancel the pending nomination of a new issuer for a collection
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction
+		/// - `collection_id`: collection id of the nft to cancel the issuer change of
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn cancel_collection_issuer_change(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			PendingCollectionIssuers::<T>::take(collection_id)
+				.ok_or(Error::<T>::NoIssuerChangePending)?;
+
+			Self::deposit_event(Event::IssuerChangeCancelled { issuer: sender, collection_id });
+			Ok(())
+		}
+
+		/// Replace the metadata of an NFT, only the issuer of the collection can edit it
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection ID of the NFT
+		/// - `nft_id`: NFT ID
+		/// - `metadata`: Arbitrary data about an nft, e.g. IPFS hash
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,1))]
+		#[transactional]
+		pub fn set_nft_metadata(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			metadata: StringLimitOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+			Self::nft_metadata_set(collection_id, nft_id, metadata)
+		}
+
+		/// Replace the metadata of a collection, only its issuer can edit it
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection ID
+		/// - `metadata`: Arbitrary data about a collection, e.g. IPFS hash
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
+		#[transactional]
+		pub fn set_collection_metadata(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			metadata: StringLimitOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+			Self::collection_metadata_set(collection_id, metadata)
+		}
+
+		/// Permanently freeze the metadata of an NFT
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection ID of the NFT
+		/// - `nft_id`: NFT ID
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,1))]
+		#[transactional]
+		pub fn freeze_nft_metadata(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			ensure!(Self::nft_exists((collection_id, nft_id)), Error::<T>::NoAvailableNftId);
+			ensure!(
+				!Self::is_metadata_frozen(collection_id, Some(nft_id)),
+				Error::<T>::MetadataFrozen
+			);
+
+			FrozenNftMetadata::<T>::insert(collection_id, nft_id, true);
+
+			Self::deposit_event(Event::NftMetadataFrozen { collection_id, nft_id });
+			Ok(())
+		}
+
+		/// Permanently freeze the metadata of a collection and of all of its NFTs
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection ID
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
+		#[transactional]
+		pub fn freeze_collection_metadata(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			ensure!(!Self::is_metadata_frozen(collection_id, None), Error::<T>::MetadataFrozen);
+
+			FrozenCollectionMetadata::<T>::insert(collection_id, true);
+
+			Self::deposit_event(Event::CollectionMetadataFrozen { collection_id });
+			Ok(())
+		}
+
+		/// Set up the delayed reveal of the metadata of a collection minted with placeholder
+		/// metadata. Only the issuer can set it, and can replace it until the reveal
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection ID
+		/// - `source`: Base URI of the revealed metadata, or hash of the base URI to disclose at
+		///   reveal
+		/// - `reveal_at`: Optional block from which any account can trigger the reveal
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,1))]
+		#[transactional]
+		pub fn set_reveal(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			source: RevealSourceOf<T>,
+			reveal_at: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+			Self::reveal_set(collection_id, source, reveal_at)
+		}
+
+		/// Reveal the metadata of a collection: the metadata of each NFT becomes the base URI
+		/// followed by the NFT id, NFTs with frozen metadata or locked are skipped. The issuer can
+		/// reveal at any time, other accounts from the scheduled reveal block
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection ID
+		/// - `base_uri`: The base URI matching the committed hash, ignored if the base URI was set
+		///   in advance
+		/// - `witness_nfts`: Upper bound of the number of NFTs in the collection
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(3, 1)
+				+ T::DbWeight::get().reads_writes(3, 1).saturating_mul(*witness_nfts as u64)
+		)]
+		#[transactional]
+		pub fn reveal(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			base_uri: Option<StringLimitOf<T>>,
+			witness_nfts: u32,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::collection_reveal_apply(sender, collection_id, base_uri, witness_nfts)
+		}
+
 		/// set a custom value on an NFT
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
@@ -675,6 +1243,83 @@ pub mod pallet {
 			Self::deposit_event(Event::PropertySet { collection_id, maybe_nft_id, key, value });
 			Ok(())
 		}
+
+		/// Remove a property from a collection or an NFT, according to the mutability of the
+		/// property
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection ID
+		/// - `maybe_nft_id`: NFT ID, or `None` for a collection property
+		/// - `key`: Key of the property
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,1))]
+		#[transactional]
+		pub fn remove_property(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_id: CollectionId,
+			maybe_nft_id: Option<NftId>,
+			key: KeyLimitOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::ensure_property_editable(&sender, collection_id, maybe_nft_id, &key)?;
+			ensure!(
+				Properties::<T>::contains_key((collection_id, maybe_nft_id, &key)),
+				Error::<T>::PropertyDoesntExist
+			);
+
+			Self::do_remove_property(collection_id, maybe_nft_id, key)
+		}
+
+		/// Set who can set or remove a property: the issuer, the owner or nobody. The accounts
+		/// allowed to edit a property can change its mutability, an immutable property stays
+		/// immutable. At most `MaxPropertyPermissions` permissions can be set on a collection or
+		/// on an NFT
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection ID
+		/// - `maybe_nft_id`: NFT ID, or `None` for a collection property
+		/// - `key`: Key of the property
+		/// - `mutability`: Issuer, Owner or Immutable
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,2))]
+		#[transactional]
+		pub fn set_property_mutability(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_id: CollectionId,
+			maybe_nft_id: Option<NftId>,
+			key: KeyLimitOf<T>,
+			mutability: PropertyMutability,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			if let Some(nft_id) = maybe_nft_id {
+				ensure!(Self::nft_exists((collection_id, nft_id)), Error::<T>::NoAvailableNftId);
+			}
+			Self::ensure_property_editable(&sender, collection_id, maybe_nft_id, &key)?;
+
+			if !PropertyPermissions::<T>::contains_key((collection_id, maybe_nft_id, &key)) {
+				PropertyPermissionsCount::<T>::try_mutate(
+					(collection_id, maybe_nft_id),
+					|count| -> DispatchResult {
+						ensure!(
+							*count < T::MaxPropertyPermissions::get(),
+							Error::<T>::TooManyPropertyPermissions
+						);
+						*count += 1;
+						Ok(())
+					},
+				)?;
+			}
+			PropertyPermissions::<T>::insert((collection_id, maybe_nft_id, &key), mutability);
+
+			Self::deposit_event(Event::PropertyMutabilitySet {
+				collection_id,
+				maybe_nft_id,
+				key,
+				mutability,
+			});
+			Ok(())
+		}
+
 		/// lock collection
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
@@ -846,5 +1491,222 @@ pub mod pallet {
 			Self::deposit_event(Event::PrioritySet { collection_id, nft_id });
 			Ok(())
 		}
+
+		/// Grant an account usage rights on an NFT until a given block. The user can equip and
+		/// unequip the NFT, but only the owner can transfer or burn it. The rights are kept when
+		/// the NFT changes hands and cannot be replaced before they expire.
+		///
+		/// Parameters:
+		/// - `origin`: Root owner of the NFT
+		/// - `collection_id`: Collection ID of the NFT
+		/// - `nft_id`: NFT ID
+		/// - `user`: Account to be granted the usage rights
+		/// - `expires`: Block at which the usage rights end
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,1))]
+		#[transactional]
+		pub fn set_user(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			user: T::AccountId,
+			expires: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+			ensure!(sender == root_owner, Error::<T>::NoPermission);
+
+			Self::user_set(collection_id, nft_id, user, expires)
+		}
+
+		/// Approve an operator to send an NFT on behalf of its root owner, replacing any previous
+		/// approval of the NFT. The approval is cleared when the NFT is sent or burned.
+		///
+		/// Parameters:
+		/// - `origin`: Root owner of the NFT
+		/// - `collection_id`: Collection ID of the NFT
+		/// - `nft_id`: NFT ID
+		/// - `operator`: Account allowed to send the NFT
+		/// - `expires`: Optional block at which the approval ends
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
+		#[transactional]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			operator: T::AccountId,
+			expires: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::transfer_approve(sender, collection_id, nft_id, operator, expires)
+		}
+
+		/// Cancel the transfer approval of an NFT
+		///
+		/// Parameters:
+		/// - `origin`: Root owner of the NFT, or the approved operator
+		/// - `collection_id`: Collection ID of the NFT
+		/// - `nft_id`: NFT ID
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
+		#[transactional]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let approval = TransferApprovals::<T>::get(collection_id, nft_id)
+				.ok_or(Error::<T>::ApprovalDoesntExist)?;
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+			ensure!(sender == root_owner || sender == approval.operator, Error::<T>::NoPermission);
+
+			TransferApprovals::<T>::remove(collection_id, nft_id);
+
+			Self::deposit_event(Event::ApprovalCancelled {
+				owner: approval.owner,
+				operator: approval.operator,
+				collection_id,
+				nft_id,
+			});
+			Ok(())
+		}
+
+		/// Approve or revoke an operator to send all NFTs of a collection owned by the caller,
+		/// including the NFTs the caller receives later
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the NFTs
+		/// - `collection_id`: Collection ID of the NFTs
+		/// - `operator`: Account allowed to send the NFTs
+		/// - `approved`: Whether the operator is approved or revoked
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn set_approval_for_all(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			operator: T::AccountId,
+			approved: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			if approved {
+				OperatorApprovals::<T>::insert((&sender, collection_id, &operator), ());
+			} else {
+				OperatorApprovals::<T>::remove((&sender, collection_id, &operator));
+			}
+
+			Self::deposit_event(Event::ApprovalForAll {
+				owner: sender,
+				operator,
+				collection_id,
+				approved,
+			});
+			Ok(())
+		}
+
+		/// Send or approve an NFT with a permit signed off-chain by its root owner. Any account
+		/// can relay the permit and pay its fee. The signature covers the SCALE encoding of
+		/// `(b"RmrkPermit/", genesis_hash, permit)`, and each permit must use the next nonce of
+		/// its signer.
+		///
+		/// Parameters:
+		/// - `origin`: Relayer of the permit
+		/// - `permit`: The NFT, the action to perform, the nonce and the deadline of the permit
+		/// - `signature`: Signature of the permit by its signer
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,4))]
+		#[transactional]
+		pub fn submit_permit(
+			origin: OriginFor<T>,
+			permit: PermitOf<T>,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+
+			Self::permit_verify(&permit, &signature)?;
+
+			let Permit { signer, collection_id, nft_id, action, nonce, .. } = permit;
+			match action {
+				PermitAction::Send { new_owner } =>
+					Self::nft_transfer(signer.clone(), collection_id, nft_id, new_owner)?,
+				PermitAction::Approve { operator, expires } => Self::transfer_approve(
+					signer.clone(),
+					collection_id,
+					nft_id,
+					operator,
+					expires,
+				)?,
+			}
+
+			Self::deposit_event(Event::PermitUsed { signer, relayer, nonce });
+			Ok(())
+		}
+
+		/// Grant a role on a collection to an account, only the issuer can grant roles. At most
+		/// `MaxCollectionRoles` roles can be granted on a collection
+		///
+		/// Parameters:
+		/// - `collection_id`: The collection to grant the role on
+		/// - `account`: The account receiving the role
+		/// - `role`: Admin, minter, resource manager or property editor
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,2))]
+		#[transactional]
+		pub fn grant_collection_role(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			account: T::AccountId,
+			role: CollectionRole,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+			if !CollectionRoles::<T>::contains_key((collection_id, &account, role)) {
+				CollectionRolesCount::<T>::try_mutate(collection_id, |count| -> DispatchResult {
+					ensure!(
+						*count < T::MaxCollectionRoles::get(),
+						Error::<T>::TooManyCollectionRoles
+					);
+					*count += 1;
+					Ok(())
+				})?;
+				CollectionRoles::<T>::insert((collection_id, &account, role), ());
+			}
+
+			Self::deposit_event(Event::CollectionRoleGranted { collection_id, account, role });
+			Ok(())
+		}
+
+		/// Revoke a role on a collection from an account, only the issuer can revoke roles
+		///
+		/// Parameters:
+		/// - `collection_id`: The collection to revoke the role on
+		/// - `account`: The account losing the role
+		/// - `role`: Admin, minter, resource manager or property editor
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,2))]
+		#[transactional]
+		pub fn revoke_collection_role(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			account: T::AccountId,
+			role: CollectionRole,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			ensure!(
+				CollectionRoles::<T>::contains_key((collection_id, &account, role)),
+				Error::<T>::CollectionRoleNotGranted
+			);
+
+			CollectionRoles::<T>::remove((collection_id, &account, role));
+			CollectionRolesCount::<T>::mutate(collection_id, |count| {
+				*count = count.saturating_sub(1)
+			});
+
+			Self::deposit_event(Event::CollectionRoleRevoked { collection_id, account, role });
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,41 @@
+// Copyright (C) 2021-2022 RMRK
+// This file is part of rmrk-core.
+// License: Apache 2.0 modified by RMRK, see LICENSE.md
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, StorageVersion},
+};
+use sp_std::collections::btree_map::BTreeMap;
+
+/// Migrate to storage version 1 by seeding the NFT id counter of each collection past its
+/// highest existing NFT id, so that minting without an id does not pick a taken id
+///
+/// Output:
+/// - The weight consumed by the migration
+pub fn migrate_to_v1<T: Config>() -> Weight
+where
+	T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+{
+	if Pallet::<T>::on_chain_storage_version() >= 1 {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut nfts: Weight = 0;
+	let mut next_ids = BTreeMap::<CollectionId, NftId>::new();
+	for (collection_id, nft_id) in Nfts::<T>::iter_keys() {
+		nfts += 1;
+		let next_id = next_ids.entry(collection_id).or_default();
+		*next_id = (*next_id).max(nft_id.saturating_add(1));
+	}
+
+	let collections = next_ids.len() as Weight;
+	for (collection_id, next_id) in next_ids {
+		NextNftId::<T>::insert(collection_id, next_id);
+	}
+
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(nfts.saturating_add(1), collections.saturating_add(1))
+}
@@ -15,7 +15,7 @@ use sp_core::{crypto::AccountId32, H256};
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
-	Perbill,
+	MultiSignature, MultiSigner, Perbill,
 };
 
 mod nfc {
@@ -51,6 +51,9 @@ parameter_types! {
 	pub const MaxPriorities: u32 = 3;
 	pub const CollectionSymbolLimit: u32 = 100;
 	pub const MaxResourcesOnMint: u32 = 3;
+	pub const MaxBatchMint: u32 = 3;
+	pub const MaxCollectionRoles: u32 = 4;
+	pub const MaxPropertyPermissions: u32 = 4;
 }
 
 impl pallet_rmrk_core::Config for Test {
@@ -63,6 +66,11 @@ impl pallet_rmrk_core::Config for Test {
 	type MaxPriorities = MaxPriorities;
 	type CollectionSymbolLimit = CollectionSymbolLimit;
 	type MaxResourcesOnMint = MaxResourcesOnMint;
+	type MaxBatchMint = MaxBatchMint;
+	type MaxCollectionRoles = MaxCollectionRoles;
+	type MaxPropertyPermissions = MaxPropertyPermissions;
+	type OffchainSignature = MultiSignature;
+	type OffchainPublic = MultiSigner;
 }
 
 parameter_types! {
@@ -2,9 +2,11 @@
 // This file is part of rmrk-core.
 // License: Apache 2.0 modified by RMRK, see LICENSE.md
 
+use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
+use sp_core::{crypto::AccountId32, sr25519, Pair};
 // use sp_runtime::AccountId32;
-use sp_runtime::Permill;
+use sp_runtime::{traits::Hash as HashT, MultiSignature, Permill};
 // use crate::types::ClassType;
 
 use super::*;
@@ -55,7 +57,7 @@ fn basic_mint(id: u32) -> DispatchResult {
 	RMRKCore::mint_nft(
 		Origin::signed(ALICE),
 		None, // if not specified defaults to minter
-		id,
+		Some(id),
 		COLLECTION_ID_0,
 		Some(ALICE),
 		Some(Permill::from_float(1.525)),
@@ -196,18 +198,52 @@ fn change_collection_issuer_works() {
 			RMRKCore::change_collection_issuer(Origin::signed(BOB), 0, BOB),
 			Error::<Test>::NoPermission
 		);
-		// Must set ownership acceptance with BOB before transfer due to uniques dependency
-		assert_ok!(Uniques::set_accept_ownership(Origin::signed(BOB), Some(0)));
-		// Change issuer from ALICE to BOB
+		// BOB can't accept without a nomination
+		assert_noop!(
+			RMRKCore::accept_collection_issuer(Origin::signed(BOB), 0),
+			Error::<Test>::NoIssuerChangePending
+		);
+		// ALICE nominates CHARLIE, then cancels the nomination
+		assert_ok!(RMRKCore::change_collection_issuer(Origin::signed(ALICE), 0, CHARLIE));
+		assert_noop!(
+			RMRKCore::cancel_collection_issuer_change(Origin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::cancel_collection_issuer_change(Origin::signed(ALICE), 0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::IssuerChangeCancelled {
+			issuer: ALICE,
+			collection_id: 0,
+		}));
+		assert_noop!(
+			RMRKCore::accept_collection_issuer(Origin::signed(CHARLIE), 0),
+			Error::<Test>::NoIssuerChangePending
+		);
+		// ALICE nominates BOB as new issuer
 		assert_ok!(RMRKCore::change_collection_issuer(Origin::signed(ALICE), 0, BOB));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::IssuerChangeProposed {
+			issuer: ALICE,
+			new_issuer: BOB,
+			collection_id: 0,
+		}));
+		// The issuer only changes once the nomination is accepted
+		assert_eq!(RMRKCore::collections(0).unwrap().issuer, ALICE);
+		assert_eq!(RMRKCore::pending_collection_issuer(0), Some(BOB));
+		// Only the nominee can accept
+		assert_noop!(
+			RMRKCore::accept_collection_issuer(Origin::signed(CHARLIE), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::accept_collection_issuer(Origin::signed(BOB), 0));
 		// Changing issuer should trigger IssuerChanged event
 		System::assert_last_event(MockEvent::RmrkCore(crate::Event::IssuerChanged {
 			old_issuer: ALICE,
 			new_issuer: BOB,
 			collection_id: 0,
 		}));
-		// New issuer should be Bob
+		// New issuer should be Bob, in rmrk-core and in uniques
 		assert_eq!(RMRKCore::collections(0).unwrap().issuer, BOB);
+		assert_eq!(UNQ::Pallet::<Test>::collection_owner(0), Some(BOB));
+		assert_eq!(RMRKCore::pending_collection_issuer(0), None);
 		// BOB can't change issuer if calls transfer_ownership in uniques
 		assert_noop!(
 			UNQ::Pallet::<Test>::transfer_ownership(Origin::signed(ALICE), 0, ALICE),
@@ -237,7 +273,7 @@ fn mint_nft_works() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			None,
-			1,
+			Some(1),
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(20.525)),
@@ -250,7 +286,7 @@ fn mint_nft_works() {
 			RMRKCore::mint_nft(
 				Origin::signed(BOB),
 				Some(BOB),
-				2,
+				Some(2),
 				COLLECTION_ID_0,
 				Some(CHARLIE),
 				Some(Permill::from_float(20.525)),
@@ -265,7 +301,7 @@ fn mint_nft_works() {
 			RMRKCore::mint_nft(
 				Origin::signed(ALICE),
 				Some(ALICE),
-				NFT_ID_0,
+				Some(NFT_ID_0),
 				NOT_EXISTING_CLASS_ID,
 				Some(CHARLIE),
 				Some(Permill::from_float(20.525)),
@@ -280,7 +316,7 @@ fn mint_nft_works() {
 			RMRKCore::mint_nft(
 				Origin::signed(ALICE),
 				None,
-				1,
+				Some(1),
 				COLLECTION_ID_0,
 				Some(ALICE),
 				Some(Permill::from_float(20.525)),
@@ -305,7 +341,7 @@ fn mint_directly_to_nft() {
 			RMRKCore::mint_nft_directly_to_nft(
 				Origin::signed(ALICE),
 				(0, 0),
-				NFT_ID_0,
+				Some(NFT_ID_0),
 				COLLECTION_ID_0,
 				None,
 				Some(Permill::from_float(20.525)),
@@ -320,7 +356,7 @@ fn mint_directly_to_nft() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(BOB),
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			None,
 			Some(Permill::from_float(20.525)),
@@ -339,7 +375,7 @@ fn mint_directly_to_nft() {
 		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
 			Origin::signed(ALICE),
 			(0, 0),
-			1,
+			Some(1),
 			COLLECTION_ID_0,
 			None,
 			Some(Permill::from_float(20.525)),
@@ -374,7 +410,7 @@ fn mint_directly_to_nft_with_resources() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(BOB),
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			None,
 			Some(Permill::from_float(20.525)),
@@ -394,7 +430,7 @@ fn mint_directly_to_nft_with_resources() {
 		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
 			Origin::signed(ALICE),
 			(0, 0),
-			1,
+			Some(1),
 			COLLECTION_ID_0,
 			None,
 			Some(Permill::from_float(20.525)),
@@ -431,6 +467,188 @@ fn mint_collection_max_logic_works() {
 	});
 }
 
+/// NFT: Minting without an id assigns the next free id of the collection
+#[test]
+fn mint_nft_with_next_id_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint an NFT with an explicit id ahead of the counter, which moves past it
+		assert_ok!(basic_mint(1));
+		assert_eq!(RMRKCore::next_nft_id(COLLECTION_ID_0), 2);
+		// Mint three NFTs without specifying an id
+		for expected_id in [2, 3, 4] {
+			assert_ok!(RMRKCore::mint_nft(
+				Origin::signed(ALICE),
+				None,
+				None,
+				COLLECTION_ID_0,
+				None,
+				None,
+				bvec![0u8; 20],
+				true,
+				None,
+			));
+			// The assigned id is reported in the NftMinted event
+			System::assert_last_event(MockEvent::RmrkCore(crate::Event::NftMinted {
+				owner: AccountIdOrCollectionNftTuple::AccountId(ALICE),
+				collection_id: COLLECTION_ID_0,
+				nft_id: expected_id,
+			}));
+			assert!(RMRKCore::nfts(COLLECTION_ID_0, expected_id).is_some());
+		}
+		// The counter points past the last assigned id
+		assert_eq!(RMRKCore::next_nft_id(COLLECTION_ID_0), 5);
+		// Minting an explicit id that is already taken still fails
+		assert_noop!(basic_mint(2), Error::<Test>::NftAlreadyExists);
+		// An explicit id behind the counter can still be minted, leaving the counter as is
+		assert_ok!(basic_mint(0));
+		assert_eq!(RMRKCore::next_nft_id(COLLECTION_ID_0), 5);
+	});
+}
+
+/// NFT: The last NFT id cannot be minted, as the counter cannot move past it
+#[test]
+fn mint_nft_with_max_id_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_noop!(basic_mint(NftId::MAX), Error::<Test>::NoAvailableNftId);
+		assert_eq!(RMRKCore::next_nft_id(COLLECTION_ID_0), 0);
+	});
+}
+
+/// NFT: The migration to storage version 1 seeds the NFT id counters
+#[test]
+fn migration_to_v1_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+		// NFTs minted before the counter existed
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(3));
+		NextNftId::<Test>::remove(COLLECTION_ID_0);
+		StorageVersion::new(0).put::<RMRKCore>();
+
+		migration::migrate_to_v1::<Test>();
+		assert_eq!(RMRKCore::on_chain_storage_version(), 1);
+		// The counter is past the highest existing id, so minting without an id works
+		assert_eq!(RMRKCore::next_nft_id(COLLECTION_ID_0), 4);
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			None,
+			None,
+			COLLECTION_ID_0,
+			None,
+			None,
+			bvec![0u8; 20],
+			true,
+			None,
+		));
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some());
+	});
+}
+
+/// NFT: Batch minting assigns the next free ids and is all-or-nothing
+#[test]
+fn mint_batch_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		let entry = |owner: Option<AccountId32>| -> MintEntryOf<Test> {
+			MintEntry {
+				owner,
+				royalty_recipient: None,
+				royalty: Some(Permill::from_float(1.525)),
+				metadata: bvec![0u8; 20],
+				transferable: true,
+				resources: None,
+			}
+		};
+		// Non-issuer cannot mint a batch
+		assert_noop!(
+			RMRKCore::mint_batch(Origin::signed(BOB), COLLECTION_ID_0, bvec![entry(None)]),
+			Error::<Test>::NoPermission
+		);
+		// Mint a batch of three NFTs, the second one to BOB
+		assert_ok!(RMRKCore::mint_batch(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			bvec![entry(None), entry(Some(BOB)), entry(None)]
+		));
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 3);
+		assert_eq!(
+			RMRKCore::nfts(COLLECTION_ID_0, 0).unwrap().owner,
+			AccountIdOrCollectionNftTuple::AccountId(ALICE)
+		);
+		assert_eq!(
+			RMRKCore::nfts(COLLECTION_ID_0, 1).unwrap().owner,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		);
+		// Minting to another account than the issuer is pending, like a single mint
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, 1).unwrap().pending);
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NftMinted {
+			owner: AccountIdOrCollectionNftTuple::AccountId(ALICE),
+			collection_id: COLLECTION_ID_0,
+			nft_id: 2,
+		}));
+		// A batch exceeding the collection max (5) reverts every mint
+		assert_noop!(
+			RMRKCore::mint_batch(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				bvec![entry(None), entry(None), entry(None)]
+			),
+			Error::<Test>::CollectionFullOrLocked
+		);
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 3);
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_none());
+	});
+}
+
+/// NFT: Airdrop mints one template to each recipient
+#[test]
+fn airdrop_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Non-issuer cannot airdrop
+		assert_noop!(
+			RMRKCore::airdrop(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				bvec![BOB],
+				None,
+				None,
+				bvec![0u8; 20],
+				true,
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+		// Airdrop to ALICE, BOB and CHARLIE
+		assert_ok!(RMRKCore::airdrop(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			bvec![ALICE, BOB, CHARLIE],
+			Some(ALICE),
+			Some(Permill::from_float(1.525)),
+			bvec![1u8; 20],
+			false,
+			None,
+		));
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 3);
+		for (nft_id, recipient) in [ALICE, BOB, CHARLIE].into_iter().enumerate() {
+			let nft = RMRKCore::nfts(COLLECTION_ID_0, nft_id as NftId).unwrap();
+			assert_eq!(nft.owner, AccountIdOrCollectionNftTuple::AccountId(recipient.clone()));
+			assert_eq!(nft.metadata.to_vec(), vec![1u8; 20]);
+			assert!(!nft.transferable);
+			// Only the NFT minted to the issuer is not pending
+			assert_eq!(nft.pending, recipient != ALICE);
+		}
+	});
+}
+
 /// NFT: Royalty defaults to self when amount provided but no recipient
 #[test]
 fn royalty_recipient_default_works() {
@@ -441,7 +659,7 @@ fn royalty_recipient_default_works() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			None, // No royalty recipient
 			Some(Permill::from_float(20.525)),
@@ -455,7 +673,7 @@ fn royalty_recipient_default_works() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
-			1,
+			Some(1),
 			COLLECTION_ID_0,
 			Some(BOB), // Royalty recipient is BOB
 			Some(Permill::from_float(20.525)),
@@ -469,7 +687,7 @@ fn royalty_recipient_default_works() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
-			2,
+			Some(2),
 			COLLECTION_ID_0,
 			None, // No royalty recipient is BOB
 			None, // No royalty amount
@@ -483,7 +701,7 @@ fn royalty_recipient_default_works() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
-			3,
+			Some(3),
 			COLLECTION_ID_0,
 			Some(ALICE), // Royalty recipient is ALICE
 			None,        // No royalty amount
@@ -656,7 +874,7 @@ fn send_non_transferable_fail() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
@@ -686,7 +904,7 @@ fn mint_non_transferrable_gem_on_to_nft_works() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(BOB),
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
@@ -699,7 +917,7 @@ fn mint_non_transferrable_gem_on_to_nft_works() {
 		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
 			Origin::signed(ALICE),
 			(0, 0),
-			1,
+			Some(1),
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
@@ -807,7 +1025,7 @@ fn reject_cannot_reject_non_pending_nft() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			None,
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
@@ -839,7 +1057,7 @@ fn reject_nft_removes_self_from_parents_children() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(BOB),
-			1,
+			Some(1),
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
@@ -1268,7 +1486,7 @@ fn add_resource_on_mint_works() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
@@ -1304,7 +1522,7 @@ fn add_resource_on_mint_beyond_max_fails() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
@@ -1325,7 +1543,7 @@ fn add_resource_pending_works() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(BOB),
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			Some(BOB),
 			Some(Permill::from_float(1.525)),
@@ -1446,7 +1664,7 @@ fn resource_removal_pending_works() {
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(BOB),
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			Some(BOB),
 			Some(Permill::from_float(1.525)),
@@ -1613,6 +1831,178 @@ fn remove_property_with_internal_works() {
 	});
 }
 
+#[test]
+fn property_permissions_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let key = stbk("test-key");
+		let value = stb("test-value");
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT and send it to BOB
+		assert_ok!(basic_mint(0));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		));
+		// Properties are issuer-mutable by default
+		assert_eq!(
+			RMRKCore::property_mutability((0, Some(0), key.clone())),
+			PropertyMutability::Issuer
+		);
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(ALICE),
+			0,
+			Some(0),
+			key.clone(),
+			value.clone()
+		));
+		// BOB owns the NFT but cannot remove an issuer-mutable property
+		assert_noop!(
+			RMRKCore::remove_property(Origin::signed(BOB), 0, Some(0), key.clone()),
+			Error::<Test>::NoPermission
+		);
+		// Nor change its mutability
+		assert_noop!(
+			RMRKCore::set_property_mutability(
+				Origin::signed(BOB),
+				0,
+				Some(0),
+				key.clone(),
+				PropertyMutability::Owner
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE hands the property over to the owner
+		assert_ok!(RMRKCore::set_property_mutability(
+			Origin::signed(ALICE),
+			0,
+			Some(0),
+			key.clone(),
+			PropertyMutability::Owner
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertyMutabilitySet {
+			collection_id: 0,
+			maybe_nft_id: Some(0),
+			key: key.clone(),
+			mutability: PropertyMutability::Owner,
+		}));
+		// ALICE can no longer edit the property, BOB can
+		assert_noop!(
+			RMRKCore::set_property(Origin::signed(ALICE), 0, Some(0), key.clone(), value.clone()),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(BOB),
+			0,
+			Some(0),
+			key.clone(),
+			stb("bob-value")
+		));
+		assert_eq!(RMRKCore::properties((0, Some(0), key.clone())).unwrap(), stb("bob-value"));
+		// BOB removes the property
+		assert_ok!(RMRKCore::remove_property(Origin::signed(BOB), 0, Some(0), key.clone()));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertyRemoved {
+			collection_id: 0,
+			maybe_nft_id: Some(0),
+			key: key.clone(),
+		}));
+		assert_eq!(RMRKCore::properties((0, Some(0), key.clone())), None);
+		// Removing a missing property fails
+		assert_noop!(
+			RMRKCore::remove_property(Origin::signed(BOB), 0, Some(0), key.clone()),
+			Error::<Test>::PropertyDoesntExist
+		);
+		// BOB sets the property again and makes it immutable
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(BOB),
+			0,
+			Some(0),
+			key.clone(),
+			value.clone()
+		));
+		assert_ok!(RMRKCore::set_property_mutability(
+			Origin::signed(BOB),
+			0,
+			Some(0),
+			key.clone(),
+			PropertyMutability::Immutable
+		));
+		// Nobody can edit, remove or unfreeze an immutable property
+		assert_noop!(
+			RMRKCore::set_property(Origin::signed(BOB), 0, Some(0), key.clone(), value.clone()),
+			Error::<Test>::PropertyImmutable
+		);
+		assert_noop!(
+			RMRKCore::remove_property(Origin::signed(ALICE), 0, Some(0), key.clone()),
+			Error::<Test>::PropertyImmutable
+		);
+		assert_noop!(
+			RMRKCore::set_property_mutability(
+				Origin::signed(BOB),
+				0,
+				Some(0),
+				key.clone(),
+				PropertyMutability::Owner
+			),
+			Error::<Test>::PropertyImmutable
+		);
+		// Collection properties follow the same rules, with the issuer as owner
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(ALICE),
+			0,
+			None,
+			key.clone(),
+			value.clone()
+		));
+		assert_ok!(RMRKCore::set_property_mutability(
+			Origin::signed(ALICE),
+			0,
+			None,
+			key.clone(),
+			PropertyMutability::Immutable
+		));
+		assert_noop!(
+			RMRKCore::remove_property(Origin::signed(ALICE), 0, None, key.clone()),
+			Error::<Test>::PropertyImmutable
+		);
+		// The NFT property permissions are cleared when the NFT is burned
+		assert_eq!(RMRKCore::property_permissions_count((0, Some(0))), 1);
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(BOB), 0, 0, MAX_BURNS));
+		assert_eq!(RMRKCore::property_permissions_count((0, Some(0))), 0);
+		assert_eq!(RMRKCore::property_mutability((0, Some(0), key)), PropertyMutability::Issuer);
+		// At most MaxPropertyPermissions permissions are set on the collection
+		for key in ["key-1", "key-2", "key-3"] {
+			assert_ok!(RMRKCore::set_property_mutability(
+				Origin::signed(ALICE),
+				0,
+				None,
+				stbk(key),
+				PropertyMutability::Owner
+			));
+		}
+		assert_eq!(RMRKCore::property_permissions_count((0, None)), 4);
+		assert_noop!(
+			RMRKCore::set_property_mutability(
+				Origin::signed(ALICE),
+				0,
+				None,
+				stbk("key-4"),
+				PropertyMutability::Owner
+			),
+			Error::<Test>::TooManyPropertyPermissions
+		);
+		// The collection property permissions are cleared when the collection is destroyed
+		assert_ok!(RMRKCore::destroy_collection(Origin::signed(ALICE), 0));
+		assert_eq!(RMRKCore::property_permissions_count((0, None)), 0);
+		assert_eq!(
+			RMRKCore::property_mutability((0, None, stbk("key-1"))),
+			PropertyMutability::Issuer
+		);
+	});
+}
+
 /// Priority: Setting priority tests (RMRK2.0 spec: SETPRIORITY)
 #[test]
 fn set_priority_works() {
@@ -1658,3 +2048,721 @@ fn set_priority_works() {
 		assert!(RMRKCore::priorities((COLLECTION_ID_0, NFT_ID_0, 500)).is_none(),);
 	});
 }
+
+/// User: set, expire, keep on send, remove on burn
+#[test]
+fn set_user_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint(0));
+		// BOB does not own NFT so attempt to set a user should fail
+		assert_noop!(
+			RMRKCore::set_user(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, BOB, 10),
+			Error::<Test>::NoPermission
+		);
+		// Usage rights must expire after the current block
+		assert_noop!(
+			RMRKCore::set_user(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, BOB, 1),
+			Error::<Test>::InvalidUserExpiry
+		);
+		// ALICE grants BOB usage rights until block 10
+		assert_ok!(RMRKCore::set_user(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, BOB, 10));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::UserSet {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			user: BOB,
+			expires: 10,
+		}));
+		assert_eq!(RMRKCore::user_of(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		assert!(RMRKCore::is_owner_or_user(&BOB, COLLECTION_ID_0, NFT_ID_0).unwrap());
+		assert!(!RMRKCore::is_owner_or_user(&CHARLIE, COLLECTION_ID_0, NFT_ID_0).unwrap());
+		// The usage rights cannot be replaced before they expire
+		assert_noop!(
+			RMRKCore::set_user(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, CHARLIE, 20),
+			Error::<Test>::UserStillActive
+		);
+		// The user cannot send or burn the NFT
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(BOB),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			RMRKCore::burn_nft(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS),
+			Error::<Test>::NoPermission
+		);
+		// The usage rights are kept when the NFT is sent
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+		));
+		assert_eq!(RMRKCore::user_of(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		// The usage rights end at the expiry block, then a new user can be set
+		System::set_block_number(10);
+		assert_eq!(RMRKCore::user_of(COLLECTION_ID_0, NFT_ID_0), None);
+		assert!(!RMRKCore::is_owner_or_user(&BOB, COLLECTION_ID_0, NFT_ID_0).unwrap());
+		assert_ok!(RMRKCore::set_user(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			ALICE,
+			20
+		));
+		// Burning the NFT removes its user
+		assert_ok!(RMRKCore::burn_nft(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			MAX_BURNS
+		));
+		assert!(RMRKCore::nft_users(COLLECTION_ID_0, NFT_ID_0).is_none());
+	});
+}
+
+/// Approval: approve, send by operator, expire, cancel
+#[test]
+fn approve_transfer_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		let send_to = |sender, nft_id, recipient| {
+			RMRKCore::send(
+				Origin::signed(sender),
+				COLLECTION_ID_0,
+				nft_id,
+				AccountIdOrCollectionNftTuple::AccountId(recipient),
+			)
+		};
+		// BOB does not own NFT so attempt to approve an operator should fail
+		assert_noop!(
+			RMRKCore::approve_transfer(Origin::signed(BOB), COLLECTION_ID_0, 0, BOB, None),
+			Error::<Test>::NoPermission
+		);
+		// The approval must expire after the current block
+		assert_noop!(
+			RMRKCore::approve_transfer(Origin::signed(ALICE), COLLECTION_ID_0, 0, BOB, Some(1)),
+			Error::<Test>::InvalidApprovalExpiry
+		);
+		// BOB cannot send ALICE's NFT without an approval
+		assert_noop!(send_to(BOB, 0, CHARLIE), Error::<Test>::NoPermission);
+		// ALICE approves BOB to send NFT 0
+		assert_ok!(RMRKCore::approve_transfer(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			0,
+			BOB,
+			None
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::TransferApproved {
+			owner: ALICE,
+			operator: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: 0,
+			expires: None,
+		}));
+		// The approval only covers NFT 0
+		assert_noop!(send_to(BOB, 1, CHARLIE), Error::<Test>::NoPermission);
+		// BOB sends NFT 0 to CHARLIE, which clears the approval
+		assert_ok!(send_to(BOB, 0, CHARLIE));
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, 0), Some(CHARLIE));
+		assert!(RMRKCore::transfer_approvals(COLLECTION_ID_0, 0).is_none());
+		assert_noop!(send_to(BOB, 0, BOB), Error::<Test>::NoPermission);
+		// An approval cannot be used once it expires
+		assert_ok!(RMRKCore::approve_transfer(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			BOB,
+			Some(5)
+		));
+		System::set_block_number(5);
+		assert_noop!(send_to(BOB, 1, BOB), Error::<Test>::NoPermission);
+		// Only the owner or the operator can cancel an approval
+		assert_noop!(
+			RMRKCore::cancel_approval(Origin::signed(CHARLIE), COLLECTION_ID_0, 1),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::cancel_approval(Origin::signed(BOB), COLLECTION_ID_0, 1));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ApprovalCancelled {
+			owner: ALICE,
+			operator: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: 1,
+		}));
+		assert_noop!(
+			RMRKCore::cancel_approval(Origin::signed(ALICE), COLLECTION_ID_0, 1),
+			Error::<Test>::ApprovalDoesntExist
+		);
+	});
+}
+
+/// Approval: approve and revoke an operator for all NFTs of a collection
+#[test]
+fn approval_for_all_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		let send_to = |sender, nft_id, recipient| {
+			RMRKCore::send(
+				Origin::signed(sender),
+				COLLECTION_ID_0,
+				nft_id,
+				AccountIdOrCollectionNftTuple::AccountId(recipient),
+			)
+		};
+		// ALICE approves BOB for all of her NFTs of the collection
+		assert_ok!(RMRKCore::set_approval_for_all(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			BOB,
+			true
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ApprovalForAll {
+			owner: ALICE,
+			operator: BOB,
+			collection_id: COLLECTION_ID_0,
+			approved: true,
+		}));
+		// BOB can send NFT 0, which is no longer covered once owned by CHARLIE
+		assert_ok!(send_to(BOB, 0, CHARLIE));
+		assert_noop!(send_to(BOB, 0, BOB), Error::<Test>::NoPermission);
+		// ALICE revokes BOB, who can no longer send NFT 1
+		assert_ok!(RMRKCore::set_approval_for_all(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			BOB,
+			false
+		));
+		assert_noop!(send_to(BOB, 1, BOB), Error::<Test>::NoPermission);
+	});
+}
+
+/// Permit: send and approve through a relayer
+#[test]
+fn submit_permit_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pair = sr25519::Pair::from_seed(&[7u8; 32]);
+		let signer: AccountId32 = pair.public().into();
+		let sign = |permit: &PermitOf<Test>| -> MultiSignature {
+			let payload = (functions::PERMIT_PREFIX, System::block_hash(0), permit).encode();
+			pair.sign(&payload[..]).into()
+		};
+		let permit = |action, nonce, deadline| Permit {
+			signer: signer.clone(),
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			action,
+			nonce,
+			deadline,
+		};
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT to the signer
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(signer.clone()),
+			Some(NFT_ID_0),
+			COLLECTION_ID_0,
+			None,
+			None,
+			bvec![0u8; 20],
+			true,
+			None,
+		));
+		let send_to_bob =
+			PermitAction::Send { new_owner: AccountIdOrCollectionNftTuple::AccountId(BOB) };
+		// The permit must be signed by its signer
+		let send_permit = permit(send_to_bob.clone(), 0, 10);
+		let other_permit = permit(send_to_bob.clone(), 0, 11);
+		assert_noop!(
+			RMRKCore::submit_permit(
+				Origin::signed(CHARLIE),
+				send_permit.clone(),
+				sign(&other_permit)
+			),
+			Error::<Test>::InvalidPermitSignature
+		);
+		// The permit must use the next nonce of the signer
+		let wrong_nonce = permit(send_to_bob.clone(), 1, 10);
+		assert_noop!(
+			RMRKCore::submit_permit(
+				Origin::signed(CHARLIE),
+				wrong_nonce.clone(),
+				sign(&wrong_nonce)
+			),
+			Error::<Test>::InvalidPermitNonce
+		);
+		// CHARLIE relays ALICE's permit to approve BOB
+		let approve_permit = permit(PermitAction::Approve { operator: BOB, expires: None }, 0, 10);
+		assert_ok!(RMRKCore::submit_permit(
+			Origin::signed(CHARLIE),
+			approve_permit.clone(),
+			sign(&approve_permit)
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PermitUsed {
+			signer: signer.clone(),
+			relayer: CHARLIE,
+			nonce: 0,
+		}));
+		assert_eq!(RMRKCore::transfer_approvals(COLLECTION_ID_0, NFT_ID_0).unwrap().operator, BOB);
+		assert_eq!(RMRKCore::permit_nonce(&signer), 1);
+		// A permit cannot be replayed
+		assert_noop!(
+			RMRKCore::submit_permit(
+				Origin::signed(CHARLIE),
+				approve_permit.clone(),
+				sign(&approve_permit)
+			),
+			Error::<Test>::InvalidPermitNonce
+		);
+		// A permit cannot be used after its deadline
+		let send_permit = permit(send_to_bob, 1, 10);
+		System::set_block_number(11);
+		assert_noop!(
+			RMRKCore::submit_permit(
+				Origin::signed(CHARLIE),
+				send_permit.clone(),
+				sign(&send_permit)
+			),
+			Error::<Test>::PermitExpired
+		);
+		System::set_block_number(10);
+		// CHARLIE relays the permit to send the NFT to BOB
+		assert_ok!(RMRKCore::submit_permit(
+			Origin::signed(CHARLIE),
+			send_permit.clone(),
+			sign(&send_permit)
+		));
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		assert_eq!(RMRKCore::permit_nonce(&signer), 2);
+	});
+}
+
+/// Roles: grant, use and revoke collection roles
+#[test]
+fn collection_roles_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		let basic_resource = BasicResource { metadata: stbd("bafybeiakahlc6") };
+		let key = stbk("test-key");
+		let value = stb("test-value");
+		// Only the issuer can grant roles
+		assert_noop!(
+			RMRKCore::grant_collection_role(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				BOB,
+				CollectionRole::Minter
+			),
+			Error::<Test>::NoPermission
+		);
+		// BOB cannot mint without the minter role
+		assert_noop!(
+			RMRKCore::mint_nft(
+				Origin::signed(BOB),
+				None,
+				Some(1),
+				COLLECTION_ID_0,
+				None,
+				None,
+				bvec![0u8; 20],
+				true,
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE grants the minter role to BOB
+		assert_ok!(RMRKCore::grant_collection_role(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			BOB,
+			CollectionRole::Minter
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionRoleGranted {
+			collection_id: COLLECTION_ID_0,
+			account: BOB,
+			role: CollectionRole::Minter,
+		}));
+		// BOB can mint, but not manage resources, set properties or lock the collection
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(BOB),
+			None,
+			Some(1),
+			COLLECTION_ID_0,
+			None,
+			None,
+			bvec![0u8; 20],
+			true,
+			None,
+		));
+		assert_noop!(
+			RMRKCore::add_basic_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				basic_resource.clone(),
+				0
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			RMRKCore::set_property(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				None,
+				key.clone(),
+				value.clone()
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			RMRKCore::lock_collection(Origin::signed(BOB), COLLECTION_ID_0),
+			Error::<Test>::NoPermission
+		);
+		// CHARLIE manages resources and edits properties
+		assert_ok!(RMRKCore::grant_collection_role(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			CHARLIE,
+			CollectionRole::ResourceManager
+		));
+		assert_ok!(RMRKCore::grant_collection_role(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			CHARLIE,
+			CollectionRole::PropertyEditor
+		));
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic_resource,
+			0
+		));
+		assert_ok!(RMRKCore::remove_resource(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0
+		));
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			Some(NFT_ID_0),
+			key.clone(),
+			value.clone()
+		));
+		assert_eq!(RMRKCore::properties((COLLECTION_ID_0, Some(NFT_ID_0), key)).unwrap(), value);
+		// CHARLIE cannot mint
+		assert_noop!(
+			RMRKCore::mint_nft(
+				Origin::signed(CHARLIE),
+				None,
+				Some(2),
+				COLLECTION_ID_0,
+				None,
+				None,
+				bvec![0u8; 20],
+				true,
+				None,
+			),
+			Error::<Test>::NoPermission
+		);
+		// The granted roles are listed, without the issuer
+		let holders = RMRKCore::collection_role_holders(COLLECTION_ID_0);
+		assert_eq!(holders.len(), 3);
+		assert!(holders.contains(&(BOB, CollectionRole::Minter)));
+		assert!(holders.contains(&(CHARLIE, CollectionRole::ResourceManager)));
+		assert!(holders.contains(&(CHARLIE, CollectionRole::PropertyEditor)));
+		// ALICE revokes the minter role from BOB
+		assert_ok!(RMRKCore::revoke_collection_role(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			BOB,
+			CollectionRole::Minter
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionRoleRevoked {
+			collection_id: COLLECTION_ID_0,
+			account: BOB,
+			role: CollectionRole::Minter,
+		}));
+		assert_noop!(
+			RMRKCore::revoke_collection_role(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				BOB,
+				CollectionRole::Minter
+			),
+			Error::<Test>::CollectionRoleNotGranted
+		);
+		// An admin holds every role and can lock the collection
+		assert_ok!(RMRKCore::grant_collection_role(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			BOB,
+			CollectionRole::Admin
+		));
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(BOB),
+			None,
+			Some(2),
+			COLLECTION_ID_0,
+			None,
+			None,
+			bvec![0u8; 20],
+			true,
+			None,
+		));
+		assert_ok!(RMRKCore::lock_collection(Origin::signed(BOB), COLLECTION_ID_0));
+		// At most MaxCollectionRoles roles are granted, granting a held role again is free
+		assert_ok!(RMRKCore::grant_collection_role(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			BOB,
+			CollectionRole::Minter
+		));
+		assert_eq!(RMRKCore::collection_roles_count(COLLECTION_ID_0), 4);
+		assert_ok!(RMRKCore::grant_collection_role(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			BOB,
+			CollectionRole::Minter
+		));
+		assert_noop!(
+			RMRKCore::grant_collection_role(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				CHARLIE,
+				CollectionRole::Minter
+			),
+			Error::<Test>::TooManyCollectionRoles
+		);
+		// Roles granted by ALICE are revoked when the issuer changes
+		assert_ok!(RMRKCore::change_collection_issuer(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			CHARLIE
+		));
+		assert_ok!(RMRKCore::accept_collection_issuer(Origin::signed(CHARLIE), COLLECTION_ID_0));
+		assert!(RMRKCore::collection_role_holders(COLLECTION_ID_0).is_empty());
+		assert_eq!(RMRKCore::collection_roles_count(COLLECTION_ID_0), 0);
+	});
+}
+
+/// Metadata: set and freeze NFT and collection metadata
+#[test]
+fn set_and_freeze_metadata_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		// Only the issuer can edit metadata
+		assert_noop!(
+			RMRKCore::set_nft_metadata(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, stbd("bad")),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			RMRKCore::set_collection_metadata(Origin::signed(BOB), COLLECTION_ID_0, stbd("bad")),
+			Error::<Test>::NoPermission
+		);
+		// ALICE fixes the metadata of the NFT and of the collection
+		assert_ok!(RMRKCore::set_nft_metadata(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			stbd("ipfs://fixed")
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NftMetadataSet {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			metadata: stbd("ipfs://fixed"),
+		}));
+		assert_eq!(
+			RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).unwrap().metadata,
+			stbd("ipfs://fixed")
+		);
+		assert_ok!(RMRKCore::set_collection_metadata(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			stbd("ipfs://collection")
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionMetadataSet {
+			collection_id: COLLECTION_ID_0,
+			metadata: stbd("ipfs://collection"),
+		}));
+		assert_eq!(
+			RMRKCore::collections(COLLECTION_ID_0).unwrap().metadata,
+			stbd("ipfs://collection")
+		);
+		// Unknown NFTs cannot be edited
+		assert_noop!(
+			RMRKCore::set_nft_metadata(Origin::signed(ALICE), COLLECTION_ID_0, 42, stbd("x")),
+			Error::<Test>::NoAvailableNftId
+		);
+		// Freezing an NFT stops further edits of that NFT only
+		assert_noop!(
+			RMRKCore::freeze_nft_metadata(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RMRKCore::freeze_nft_metadata(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NftMetadataFrozen {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert_noop!(
+			RMRKCore::set_nft_metadata(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, stbd("x")),
+			Error::<Test>::MetadataFrozen
+		);
+		assert_noop!(
+			RMRKCore::freeze_nft_metadata(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::MetadataFrozen
+		);
+		assert_ok!(RMRKCore::set_nft_metadata(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			stbd("x")
+		));
+		// Freezing the collection stops edits of the collection and of all of its NFTs
+		assert_ok!(RMRKCore::freeze_collection_metadata(Origin::signed(ALICE), COLLECTION_ID_0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionMetadataFrozen {
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert_noop!(
+			RMRKCore::set_collection_metadata(Origin::signed(ALICE), COLLECTION_ID_0, stbd("x")),
+			Error::<Test>::MetadataFrozen
+		);
+		assert_noop!(
+			RMRKCore::set_nft_metadata(Origin::signed(ALICE), COLLECTION_ID_0, 1, stbd("y")),
+			Error::<Test>::MetadataFrozen
+		);
+	});
+}
+
+/// Reveal: reveal a base URI set in advance, by anyone at the scheduled block
+#[test]
+fn reveal_with_base_uri_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(basic_collection());
+		for id in 0..3 {
+			assert_ok!(basic_mint(id));
+		}
+		// NFT 2 keeps its metadata once frozen
+		assert_ok!(RMRKCore::freeze_nft_metadata(Origin::signed(ALICE), COLLECTION_ID_0, 2));
+		// NFT 1 keeps its metadata while locked
+		RMRKCore::set_lock((COLLECTION_ID_0, 1), true);
+		let source = RevealSource::BaseUri(stbd("ipfs://base/"));
+		// Reveal must be set first, by the issuer, at a future block
+		assert_noop!(
+			RMRKCore::reveal(Origin::signed(ALICE), COLLECTION_ID_0, None, 3),
+			Error::<Test>::NoRevealPending
+		);
+		assert_noop!(
+			RMRKCore::set_reveal(Origin::signed(BOB), COLLECTION_ID_0, source.clone(), Some(10)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			RMRKCore::set_reveal(Origin::signed(ALICE), COLLECTION_ID_0, source.clone(), Some(1)),
+			Error::<Test>::InvalidRevealBlock
+		);
+		assert_ok!(RMRKCore::set_reveal(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			source.clone(),
+			Some(10)
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::RevealSet {
+			collection_id: COLLECTION_ID_0,
+			reveal_at: Some(10),
+		}));
+		// BOB cannot reveal before the scheduled block
+		assert_noop!(
+			RMRKCore::reveal(Origin::signed(BOB), COLLECTION_ID_0, None, 3),
+			Error::<Test>::RevealNotDue
+		);
+		System::set_block_number(10);
+		// The witness must cover every NFT of the collection
+		assert_noop!(
+			RMRKCore::reveal(Origin::signed(BOB), COLLECTION_ID_0, None, 2),
+			Error::<Test>::InvalidRevealWitness
+		);
+		assert_ok!(RMRKCore::reveal(Origin::signed(BOB), COLLECTION_ID_0, None, 3));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionRevealed {
+			collection_id: COLLECTION_ID_0,
+			base_uri: stbd("ipfs://base/"),
+		}));
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).unwrap().metadata, stbd("ipfs://base/0"));
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).unwrap().metadata.to_vec(), vec![0u8; 20]);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).unwrap().metadata.to_vec(), vec![0u8; 20]);
+		// A collection is revealed only once
+		assert_noop!(
+			RMRKCore::reveal(Origin::signed(ALICE), COLLECTION_ID_0, None, 3),
+			Error::<Test>::CollectionAlreadyRevealed
+		);
+		assert_noop!(
+			RMRKCore::set_reveal(Origin::signed(ALICE), COLLECTION_ID_0, source, None),
+			Error::<Test>::CollectionAlreadyRevealed
+		);
+	});
+}
+
+/// Reveal: reveal a committed base URI, checked against its hash
+#[test]
+fn reveal_with_committed_hash_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint(0));
+		let base_uri = stbd("ipfs://secret/");
+		let commitment = <Test as frame_system::Config>::Hashing::hash(&base_uri);
+		assert_ok!(RMRKCore::set_reveal(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			RevealSource::Committed(commitment),
+			None
+		));
+		// Without a scheduled block only the issuer can reveal
+		assert_noop!(
+			RMRKCore::reveal(Origin::signed(BOB), COLLECTION_ID_0, Some(base_uri.clone()), 1),
+			Error::<Test>::RevealNotDue
+		);
+		// The base URI must match the committed hash
+		assert_noop!(
+			RMRKCore::reveal(Origin::signed(ALICE), COLLECTION_ID_0, None, 1),
+			Error::<Test>::InvalidRevealUri
+		);
+		assert_noop!(
+			RMRKCore::reveal(Origin::signed(ALICE), COLLECTION_ID_0, Some(stbd("ipfs://fake/")), 1),
+			Error::<Test>::InvalidRevealUri
+		);
+		assert_ok!(RMRKCore::reveal(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			Some(base_uri.clone()),
+			1
+		));
+		assert_eq!(
+			RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).unwrap().metadata,
+			stbd("ipfs://secret/0")
+		);
+		// The disclosed base URI is kept with the revealed state
+		let reveal = RMRKCore::collection_reveal(COLLECTION_ID_0).unwrap();
+		assert_eq!(reveal.source, RevealSource::BaseUri(base_uri));
+		assert_eq!(reveal.state, RevealState::Revealed);
+	});
+}
@@ -3,6 +3,7 @@
 // License: Apache 2.0 modified by RMRK, see LICENSE.md
 
 use frame_support::pallet_prelude::*;
+use rmrk_traits::{primitives::*, AccountIdOrCollectionNftTuple};
 use sp_runtime::Permill;
 
 use scale_info::TypeInfo;
@@ -28,3 +29,130 @@ pub struct InstanceInfo<AccountId, BoundedString> {
 	/// Arbitrary data about an instance, e.g. IPFS hash
 	pub metadata: BoundedString,
 }
+
+/// Usage rights granted on an NFT until a given block
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct UserInfo<AccountId, BlockNumber> {
+	/// The account allowed to use, but not transfer or burn, the NFT
+	pub user: AccountId,
+	/// The block at which the usage rights end
+	pub expires: BlockNumber,
+}
+
+/// Approval for an operator to send an NFT on behalf of its root owner
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TransferApproval<AccountId, BlockNumber> {
+	/// The root owner who granted the approval, it is void once the NFT changes hands
+	pub owner: AccountId,
+	/// The account allowed to send the NFT
+	pub operator: AccountId,
+	/// Optional block at which the approval ends
+	pub expires: Option<BlockNumber>,
+}
+
+/// Action authorized by a permit
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub enum PermitAction<AccountId, BlockNumber> {
+	/// Send the NFT to a new owner
+	Send { new_owner: AccountIdOrCollectionNftTuple<AccountId> },
+	/// Approve an operator to send the NFT
+	Approve { operator: AccountId, expires: Option<BlockNumber> },
+}
+
+/// Transfer or approval signed off-chain by the root owner of an NFT, to be submitted by a
+/// relayer
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct Permit<AccountId, BlockNumber> {
+	/// The root owner of the NFT who signed the permit
+	pub signer: AccountId,
+	/// Collection of the NFT
+	pub collection_id: CollectionId,
+	/// The NFT sent or approved
+	pub nft_id: NftId,
+	/// The transfer or approval authorized by the signer
+	pub action: PermitAction<AccountId, BlockNumber>,
+	/// Must match the next permit nonce of the signer
+	pub nonce: u64,
+	/// Last block at which the permit can be submitted
+	pub deadline: BlockNumber,
+}
+
+/// A single NFT to mint as part of a batch
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct MintEntry<AccountId, BoundedString, BoundedResources> {
+	/// Owner of the NFT, defaults to the minter if `None`
+	pub owner: Option<AccountId>,
+	/// Receiver of the royalty, defaults to the owner if `None`
+	pub royalty_recipient: Option<AccountId>,
+	/// Permillage reward from each trade for the royalty recipient
+	pub royalty: Option<Permill>,
+	/// Arbitrary data about an NFT, e.g. IPFS hash
+	pub metadata: BoundedString,
+	/// Whether the NFT can be sent after minting
+	pub transferable: bool,
+	/// Resources added to the NFT on mint
+	pub resources: Option<BoundedResources>,
+}
+
+/// Role on a collection, granted by its issuer who implicitly holds every role
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CollectionRole {
+	/// Holds every other role and can lock the collection
+	Admin,
+	/// Can mint NFTs in the collection
+	Minter,
+	/// Can add and remove resources on the NFTs of the collection
+	ResourceManager,
+	/// Can set properties on the collection and its NFTs
+	PropertyEditor,
+}
+
+/// Where the revealed metadata of a collection comes from
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub enum RevealSource<BoundedString, Hash> {
+	/// Base URI known in advance, the metadata of each NFT is the base URI followed by its id
+	BaseUri(BoundedString),
+	/// Hash of the base URI, which is only disclosed and checked at reveal
+	Committed(Hash),
+}
+
+/// State of the reveal of a collection
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RevealState {
+	/// NFTs still have their placeholder metadata
+	Pending,
+	/// NFTs have their final metadata
+	Revealed,
+}
+
+/// Delayed reveal of the metadata of a collection
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct RevealInfo<BoundedString, Hash, BlockNumber> {
+	/// Base URI or committed hash of the revealed metadata
+	pub source: RevealSource<BoundedString, Hash>,
+	/// Block from which any account can trigger the reveal, the issuer can reveal at any time
+	pub reveal_at: Option<BlockNumber>,
+	/// Whether the collection is revealed yet
+	pub state: RevealState,
+}
+
+/// Who can set or remove a property
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PropertyMutability {
+	/// The issuer of the collection and its property editors
+	Issuer,
+	/// The root owner of the NFT, or the issuer for a collection property
+	Owner,
+	/// Nobody, the property can no longer change
+	Immutable,
+}
+
+impl Default for PropertyMutability {
+	fn default() -> Self {
+		PropertyMutability::Issuer
+	}
+}
@@ -222,17 +222,25 @@ where
 			Error::<T>::SlotAlreadyEquipped
 		);
 
-		// Caller must root-own item
-		let item_owner =
-			pallet_rmrk_core::Pallet::<T>::lookup_root_owner(item_collection_id, item_nft_id)?;
-		ensure!(item_owner.0 == issuer, Error::<T>::PermissionError);
+		// Caller must root-own item or be its current user
+		ensure!(
+			pallet_rmrk_core::Pallet::<T>::is_owner_or_user(
+				&issuer,
+				item_collection_id,
+				item_nft_id
+			)?,
+			Error::<T>::PermissionError
+		);
 
-		// Caller must root-own equipper
-		let equipper_owner = pallet_rmrk_core::Pallet::<T>::lookup_root_owner(
-			equipper_collection_id,
-			equipper_nft_id,
-		)?;
-		ensure!(equipper_owner.0 == issuer, Error::<T>::PermissionError);
+		// Caller must root-own equipper or be its current user
+		ensure!(
+			pallet_rmrk_core::Pallet::<T>::is_owner_or_user(
+				&issuer,
+				equipper_collection_id,
+				equipper_nft_id
+			)?,
+			Error::<T>::PermissionError
+		);
 
 		// Equipper must be direct parent of item
 		let equipper_owner = pallet_rmrk_core::Pallet::<T>::nfts(item_collection_id, item_nft_id)
@@ -383,15 +391,19 @@ where
 			return Ok((item_collection_id, item_nft_id, base_id, slot_id))
 		}
 
-		let item_owner =
-			pallet_rmrk_core::Pallet::<T>::lookup_root_owner(item_collection_id, item_nft_id)?;
-		let equipper_owner = pallet_rmrk_core::Pallet::<T>::lookup_root_owner(
+		// Current users count as owners here, so a renter can unequip what they equipped
+		let issuer_uses_item = pallet_rmrk_core::Pallet::<T>::is_owner_or_user(
+			&issuer,
+			item_collection_id,
+			item_nft_id,
+		)?;
+		let issuer_uses_equipper = pallet_rmrk_core::Pallet::<T>::is_owner_or_user(
+			&issuer,
 			equipper_collection_id,
 			equipper_nft_id,
 		)?;
 
-		let issuer_owns_either_equipper_or_item =
-			item_owner.0 == issuer || equipper_owner.0 == issuer;
+		let issuer_owns_either_equipper_or_item = issuer_uses_item || issuer_uses_equipper;
 		ensure!(
 			issuer_owns_either_equipper_or_item,
 			Error::<T>::UnequipperMustOwnEitherItemOrEquipper
@@ -147,6 +147,12 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn pending_base_issuer)]
+	/// Stores the account nominated by the issuer of a Base to become its new issuer
+	pub type PendingBaseIssuers<T: Config> =
+		StorageMap<_, Twox64Concat, BaseId, T::AccountId, OptionQuery>;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	pub struct Pallet<T>(_);
@@ -178,6 +184,17 @@ pub mod pallet {
 			base_id: BaseId,
 			slot_id: SlotId,
 		},
+		// A new issuer was nominated for a Base
+		BaseIssuerChangeProposed {
+			issuer: T::AccountId,
+			new_issuer: T::AccountId,
+			base_id: BaseId,
+		},
+		// The nomination of a new issuer for a Base was cancelled
+		BaseIssuerChangeCancelled {
+			issuer: T::AccountId,
+			base_id: BaseId,
+		},
 		// Base's issuer has changed
 		BaseIssuerChanged {
 			old_issuer: T::AccountId,
@@ -237,6 +254,8 @@ pub mod pallet {
 		ItemNotEquipped,
 		// Cannot unequip an item when caller owns neither the item nor equipper
 		UnequipperMustOwnEitherItemOrEquipper,
+		// No new issuer is nominated for the Base
+		NoBaseIssuerChangePending,
 	}
 
 	#[pallet::call]
@@ -244,7 +263,8 @@ pub mod pallet {
 	where
 		T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
 	{
-		/// Change the issuer of a Base
+		/// Nominate a new issuer for a Base, the issuer only changes once the nominee accepts
+		/// with `accept_base_issuer`. A new nomination replaces the pending one
 		///
 		/// Parameters:
 		/// - `origin`: sender of the transaction
@@ -259,19 +279,63 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 			let base = Self::bases(base_id).ok_or(Error::<T>::BaseDoesntExist)?;
 			ensure!(base.issuer == sender, Error::<T>::PermissionError);
-			let new_owner = T::Lookup::lookup(new_issuer)?;
+			let new_issuer = T::Lookup::lookup(new_issuer)?;
 
-			ensure!(Bases::<T>::contains_key(base_id), Error::<T>::NoAvailableBaseId);
+			PendingBaseIssuers::<T>::insert(base_id, &new_issuer);
+
+			Self::deposit_event(Event::BaseIssuerChangeProposed {
+				issuer: sender,
+				new_issuer,
+				base_id,
+			});
+			Ok(())
+		}
+
+		/// Accept the nomination as new issuer of a Base
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, the nominated issuer
+		/// - `base_id`: base_id to become the issuer of
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,2))]
+		pub fn accept_base_issuer(origin: OriginFor<T>, base_id: BaseId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let base = Self::bases(base_id).ok_or(Error::<T>::BaseDoesntExist)?;
+			let nominee =
+				Self::pending_base_issuer(base_id).ok_or(Error::<T>::NoBaseIssuerChangePending)?;
+			ensure!(nominee == sender, Error::<T>::PermissionError);
 
-			let (new_owner, base_id) = Self::base_change_issuer(base_id, new_owner)?;
+			PendingBaseIssuers::<T>::remove(base_id);
+			let (new_owner, base_id) = Self::base_change_issuer(base_id, sender)?;
 
 			Self::deposit_event(Event::BaseIssuerChanged {
-				old_issuer: sender,
+				old_issuer: base.issuer,
 				new_issuer: new_owner,
 				base_id,
 			});
 			Ok(())
 		}
+
+		/// Cancel the pending nomination of a new issuer for a Base
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction
+		/// - `base_id`: base_id to cancel the issuer change of
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		pub fn cancel_base_issuer_change(origin: OriginFor<T>, base_id: BaseId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let base = Self::bases(base_id).ok_or(Error::<T>::BaseDoesntExist)?;
+			ensure!(base.issuer == sender, Error::<T>::PermissionError);
+			ensure!(
+				PendingBaseIssuers::<T>::contains_key(base_id),
+				Error::<T>::NoBaseIssuerChangePending
+			);
+
+			PendingBaseIssuers::<T>::remove(base_id);
+
+			Self::deposit_event(Event::BaseIssuerChangeCancelled { issuer: sender, base_id });
+			Ok(())
+		}
+
 		/// Equips a child NFT's resource to a parent's slot, if all are available.
 		/// Equipping operations are maintained inside the Equippings storage.
 		/// Modeled after [equip interaction](https://github.com/rmrk-team/rmrk-spec/blob/master/standards/rmrk2.0.0/interactions/equip.md)
@@ -88,8 +88,40 @@ fn change_base_issuer_works() {
 			RmrkEquip::change_base_issuer(Origin::signed(BOB), 0, BOB),
 			Error::<Test>::PermissionError
 		);
+		// Bob can't accept without a nomination
+		assert_noop!(
+			RmrkEquip::accept_base_issuer(Origin::signed(BOB), 0),
+			Error::<Test>::NoBaseIssuerChangePending
+		);
+		// Alice nominates Charlie, then cancels the nomination
+		assert_ok!(RmrkEquip::change_base_issuer(Origin::signed(ALICE), 0, CHARLIE));
+		assert_noop!(
+			RmrkEquip::cancel_base_issuer_change(Origin::signed(BOB), 0),
+			Error::<Test>::PermissionError
+		);
+		assert_ok!(RmrkEquip::cancel_base_issuer_change(Origin::signed(ALICE), 0));
+		System::assert_last_event(MockEvent::RmrkEquip(crate::Event::BaseIssuerChangeCancelled {
+			issuer: ALICE,
+			base_id: 0,
+		}));
+		assert_noop!(
+			RmrkEquip::accept_base_issuer(Origin::signed(CHARLIE), 0),
+			Error::<Test>::NoBaseIssuerChangePending
+		);
 		// Changing Base Issuer should be Alice
 		assert_ok!(RmrkEquip::change_base_issuer(Origin::signed(ALICE), 0, BOB));
+		System::assert_last_event(MockEvent::RmrkEquip(crate::Event::BaseIssuerChangeProposed {
+			issuer: ALICE,
+			new_issuer: BOB,
+			base_id: 0,
+		}));
+		// Issuer only changes once Bob accepts
+		assert_eq!(RmrkEquip::bases(0).unwrap().issuer, ALICE);
+		assert_noop!(
+			RmrkEquip::accept_base_issuer(Origin::signed(CHARLIE), 0),
+			Error::<Test>::PermissionError
+		);
+		assert_ok!(RmrkEquip::accept_base_issuer(Origin::signed(BOB), 0));
 		// Issuer should be Bob
 		assert_eq!(RmrkEquip::bases(0).unwrap().issuer, BOB);
 		// Last event should be BaseIssuerChanged
@@ -175,7 +207,7 @@ fn equip_works() {
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),                        // owner
-			0,                                  // nft id
+			Some(0),                            // nft id
 			0,                                  // collection ID
 			Some(ALICE),                        // recipient
 			Some(Permill::from_float(1.525)),   // royalties
@@ -188,7 +220,7 @@ fn equip_works() {
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),                        // owner
-			1,                                  // nft id
+			Some(1),                            // nft id
 			0,                                  // collection ID
 			Some(ALICE),                        // recipient
 			Some(Permill::from_float(1.525)),   // royalties
@@ -201,7 +233,7 @@ fn equip_works() {
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),                      // owner
-			0,                                // nft id
+			Some(0),                          // nft id
 			1,                                // collection ID
 			Some(ALICE),                      // recipient
 			Some(Permill::from_float(1.525)), // royalties
@@ -214,7 +246,7 @@ fn equip_works() {
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),                       // owner
-			1,                                 // nft id
+			Some(1),                           // nft id
 			1,                                 // collection ID
 			Some(ALICE),                       // recipient
 			Some(Permill::from_float(1.525)),  // royalties
@@ -484,6 +516,39 @@ fn equip_works() {
 			),
 			pallet_rmrk_core::Error::<Test>::CannotSendEquippedItem,
 		);
+
+		// ALICE grants CHARLIE usage rights on character-0 until block 10
+		assert_ok!(RmrkCore::set_user(Origin::signed(ALICE), 0, 0, CHARLIE, 10));
+
+		// CHARLIE can unequip and equip the sword as the user of character-0
+		assert_ok!(RmrkEquip::unequip(
+			Origin::signed(CHARLIE), // Signer
+			(1, 0),                  // item
+			(0, 0),                  // equipper
+			0,                       // BaseId
+			202,                     // SlotId
+		));
+		assert_ok!(RmrkEquip::equip(
+			Origin::signed(CHARLIE), // Signer
+			(1, 0),                  // item
+			(0, 0),                  // equipper
+			0,                       // ResourceId
+			0,                       // BaseId
+			201,                     // SlotId
+		));
+
+		// CHARLIE can't unequip the sword once the usage rights have expired
+		System::set_block_number(10);
+		assert_noop!(
+			RmrkEquip::unequip(
+				Origin::signed(CHARLIE), // Signer
+				(1, 0),                  // item
+				(0, 0),                  // equipper
+				0,                       // BaseId
+				201,                     // SlotId
+			),
+			Error::<Test>::UnequipperMustOwnEitherItemOrEquipper
+		);
 	});
 }
 
@@ -568,7 +633,7 @@ fn nested_equip_works() {
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
 			None,                             // owner
-			0,                                // nft id
+			Some(0),                          // nft id
 			0,                                // collection ID
 			Some(ALICE),                      // recipient
 			Some(Permill::from_float(1.525)), // royalties
@@ -581,7 +646,7 @@ fn nested_equip_works() {
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
 			None,                             // owner
-			0,                                // nft id
+			Some(0),                          // nft id
 			1,                                // collection ID
 			Some(ALICE),                      // recipient
 			Some(Permill::from_float(1.525)), // royalties
@@ -594,7 +659,7 @@ fn nested_equip_works() {
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
 			None,                             // owner
-			0,                                // nft id
+			Some(0),                          // nft id
 			2,                                // collection ID
 			Some(ALICE),                      // recipient
 			Some(Permill::from_float(1.525)), // royalties
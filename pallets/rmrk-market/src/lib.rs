@@ -7,12 +7,19 @@
 #![allow(clippy::upper_case_acronyms)]
 
 use frame_support::{
-	dispatch::DispatchResult,
+	dispatch::{DispatchError, DispatchResult},
 	ensure,
-	traits::{Currency, ExistenceRequirement, ReservableCurrency},
-	transactional, BoundedVec,
+	traits::{
+		tokens::fungibles, Currency, ExistenceRequirement, Imbalance, OnUnbalanced,
+		ReservableCurrency,
+	},
+	transactional, BoundedVec, PalletId,
 };
 use frame_system::{ensure_signed, RawOrigin};
+use sp_runtime::{
+	traits::{AccountIdConversion, CheckedMul, Saturating, UniqueSaturatedInto, Zero},
+	ArithmeticError, Perbill,
+};
 
 use sp_std::prelude::*;
 
@@ -20,6 +27,7 @@ pub use pallet::*;
 
 use rmrk_traits::{primitives::*, AccountIdOrCollectionNftTuple, NftInfo};
 
+pub mod migration;
 pub mod types;
 
 #[cfg(test)]
@@ -28,13 +36,15 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-use crate::types::Offer;
+use crate::types::{
+	AuctionInfo, BundleId, BundleInfo, CollectionOffer, CollectionStats, Expiring, ListInfo,
+	ListingKind, Offer, PaymentAsset, RentalInfo, SaleRecord, SwapId, SwapInfo, SwapTarget,
+};
 pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use crate::types::ListInfo;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::Permill;
@@ -48,16 +58,67 @@ pub mod pallet {
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	pub type PaymentAssetOf<T> = PaymentAsset<<T as Config>::AssetId>;
+
 	pub type ListInfoOf<T> = ListInfo<
 		<T as frame_system::Config>::AccountId,
 		BalanceOf<T>,
 		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::AssetId,
 	>;
 
 	pub type OfferOf<T> = Offer<
 		<T as frame_system::Config>::AccountId,
 		BalanceOf<T>,
 		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::AssetId,
+	>;
+
+	pub type CollectionOfferOf<T> = CollectionOffer<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	pub type ExpiringOf<T> = Expiring<<T as frame_system::Config>::AccountId>;
+
+	pub type SwapInfoOf<T> = SwapInfo<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		BoundedVec<(CollectionId, NftId), <T as Config>::MaxNftsPerSwap>,
+	>;
+
+	pub type BundleInfoOf<T> = BundleInfo<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<(CollectionId, NftId), <T as Config>::MaxNftsPerBundle>,
+	>;
+
+	pub type SaleRecordOf<T> = SaleRecord<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::AssetId,
+	>;
+
+	pub type CollectionStatsOf<T> =
+		CollectionStats<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+	pub type AuctionInfoOf<T> = AuctionInfo<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	pub type RentalInfoOf<T> = RentalInfo<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
 	>;
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -66,22 +127,82 @@ pub mod pallet {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		/// The origin which may forcibly buy, sell, list/unlist, offer & withdraw offer on Tokens
+		/// The origin which may forcibly unlist Tokens, withdraw offers, cancel all trades of a
+		/// collection and pause the Marketplace
 		type ProtocolOrigin: EnsureOrigin<Self::Origin>;
 
 		/// The market currency mechanism.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// Identifier of an asset of `Assets`
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Assets other than the native currency that NFTs can be listed and offered in
+		type Assets: fungibles::Transfer<
+			Self::AccountId,
+			AssetId = Self::AssetId,
+			Balance = BalanceOf<Self>,
+		>;
+
+		/// The market pallet id, used to derive the account holding the amounts offered in
+		/// `Assets` until the offers are accepted, withdrawn or expire
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
 		/// Minimum offer amount as a valid offer
 		#[pallet::constant]
 		type MinimumOfferAmount: Get<BalanceOf<Self>>;
 
+		/// A bid placed less than this many blocks before an auction ends extends the auction to
+		/// end this many blocks after the bid
+		#[pallet::constant]
+		type AuctionExtendPeriod: Get<Self::BlockNumber>;
+
+		/// Maximum number of auctions that can end in the same block
+		#[pallet::constant]
+		type MaxAuctionsEndingPerBlock: Get<u32>;
+
+		/// Part of the price of every sale that is paid to the `MarketplaceFeeRecipient`
+		#[pallet::constant]
+		type MarketplaceFee: Get<Permill>;
+
+		/// Account receiving the marketplace fee of every sale
+		#[pallet::constant]
+		type MarketplaceFeeRecipient: Get<Self::AccountId>;
+
+		/// Amount reserved from the owner of a listed NFT until the listing is removed
+		#[pallet::constant]
+		type ListingDeposit: Get<BalanceOf<Self>>;
+
+		/// Part of the deposit of a fraudulent listing that is slashed
+		#[pallet::constant]
+		type ListingDepositSlash: Get<Permill>;
+
+		/// Handler for the slashed listing deposits
+		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Maximum number of NFTs that can be offered in a swap
+		#[pallet::constant]
+		type MaxNftsPerSwap: Get<u32>;
+
+		/// Maximum number of NFTs that can be listed in a bundle
+		#[pallet::constant]
+		type MaxNftsPerBundle: Get<u32>;
+
+		/// Maximum number of recent sales kept in the history of a NFT and of a collection
+		#[pallet::constant]
+		type MaxSaleHistory: Get<u32>;
+
 		// TODO: Weight values for this pallet
 		// type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -97,6 +218,19 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn reserved_listings_of)]
+	/// Stores the listings reserved for an account
+	pub type ReservedListings<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(CollectionId, NftId),
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn offers)]
 	/// Stores offer on a NFT info
@@ -110,6 +244,181 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn offers_in_collection)]
+	/// Stores the NFT and maker of each offer on a NFT of a collection
+	pub type OffersInCollection<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		(NftId, T::AccountId),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_offers)]
+	/// Stores offers on any NFT of a collection
+	pub type CollectionOffers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		CollectionOfferOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn expirations)]
+	/// Stores the listings and offers expiring at a given block
+	pub type Expirations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		ExpiringOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_expiration_to_clean)]
+	/// Stores the first block whose expired listings and offers have not been cleaned up yet
+	pub type NextExpirationToClean<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_swap_id)]
+	/// Stores the id of the next swap
+	pub type NextSwapId<T: Config> = StorageValue<_, SwapId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn swaps)]
+	/// Stores open swap proposals
+	pub type Swaps<T: Config> = StorageMap<_, Twox64Concat, SwapId, SwapInfoOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn nfts_in_swaps)]
+	/// Stores the swap that locked a NFT offered in it
+	pub type NftsInSwaps<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		NftId,
+		SwapId,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn swaps_in_collection)]
+	/// Stores the open swaps offering or requesting a NFT of a collection
+	pub type SwapsInCollection<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CollectionId, Twox64Concat, SwapId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_bundle_id)]
+	/// Stores the id of the next bundle
+	pub type NextBundleId<T: Config> = StorageValue<_, BundleId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bundles)]
+	/// Stores listed bundles of NFTs
+	pub type Bundles<T: Config> =
+		StorageMap<_, Twox64Concat, BundleId, BundleInfoOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn nfts_in_bundles)]
+	/// Stores the listed bundle of a NFT
+	pub type NftsInBundles<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		NftId,
+		BundleId,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn auctions)]
+	/// Stores auctions of NFTs
+	pub type Auctions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		NftId,
+		AuctionInfoOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn auctions_ending_at)]
+	/// Stores the NFTs whose auction is settled at a given block
+	pub type AuctionsEndingAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<(CollectionId, NftId), T::MaxAuctionsEndingPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn nft_sales)]
+	/// Stores the recent sales of a NFT, from the oldest to the most recent
+	pub type NftSales<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		NftId,
+		BoundedVec<SaleRecordOf<T>, T::MaxSaleHistory>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_sales)]
+	/// Stores the recent sales of the NFTs of a collection, from the oldest to the most recent
+	pub type CollectionSales<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		BoundedVec<SaleRecordOf<T>, T::MaxSaleHistory>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_stats)]
+	/// Stores the sales statistics of a collection
+	pub type CollectionSaleStats<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, CollectionStatsOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn market_paused)]
+	/// Stores whether the whole marketplace is paused
+	pub type MarketPaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn paused_collections)]
+	/// Stores the collections whose NFTs cannot be traded on the marketplace
+	pub type PausedCollections<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn rental_listings)]
+	/// Stores NFTs listed for rent and their price per block
+	pub type RentalListings<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		NftId,
+		RentalInfoOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -120,13 +429,18 @@ pub mod pallet {
 			nft_id: NftId,
 			price: Option<BalanceOf<T>>,
 		},
-		/// Token was sold to a new owner
+		/// Token was sold to a new owner. `fee` is the part of `price` paid to the marketplace,
+		/// `royalty` holds the royalty recipient and the part of `price` they were paid, the
+		/// owner received the rest.
 		TokenSold {
 			owner: T::AccountId,
 			buyer: T::AccountId,
 			collection_id: CollectionId,
 			nft_id: NftId,
 			price: BalanceOf<T>,
+			asset: PaymentAssetOf<T>,
+			fee: BalanceOf<T>,
+			royalty: Option<(T::AccountId, BalanceOf<T>)>,
 		},
 		/// Token listed on Marketplace
 		TokenListed {
@@ -134,6 +448,27 @@ pub mod pallet {
 			collection_id: CollectionId,
 			nft_id: NftId,
 			price: BalanceOf<T>,
+			asset: PaymentAssetOf<T>,
+		},
+		/// Token listed on Marketplace in a Dutch auction, its price decreases from
+		/// `start_price` to `end_price` over `duration` blocks from the `start` block
+		TokenListedDutch {
+			owner: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			start_price: BalanceOf<T>,
+			end_price: BalanceOf<T>,
+			asset: PaymentAssetOf<T>,
+			start: T::BlockNumber,
+			duration: T::BlockNumber,
+		},
+		/// Token listing was reserved for `buyer` until the `expires` block
+		ListingReserved {
+			owner: T::AccountId,
+			buyer: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			expires: Option<T::BlockNumber>,
 		},
 		/// Token unlisted on Marketplace
 		TokenUnlisted { owner: T::AccountId, collection_id: CollectionId, nft_id: NftId },
@@ -143,6 +478,15 @@ pub mod pallet {
 			collection_id: CollectionId,
 			nft_id: NftId,
 			price: BalanceOf<T>,
+			asset: PaymentAssetOf<T>,
+		},
+		/// Amount of an offer was updated
+		OfferUpdated {
+			offerer: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			price: BalanceOf<T>,
+			asset: PaymentAssetOf<T>,
 		},
 		/// Offer was withdrawn
 		OfferWithdrawn { sender: T::AccountId, collection_id: CollectionId, nft_id: NftId },
@@ -153,6 +497,137 @@ pub mod pallet {
 			collection_id: CollectionId,
 			nft_id: NftId,
 		},
+		/// Offer was placed on any token of a collection
+		CollectionOfferPlaced {
+			offerer: T::AccountId,
+			collection_id: CollectionId,
+			price: BalanceOf<T>,
+			quantity: u32,
+		},
+		/// Collection offer was withdrawn
+		CollectionOfferWithdrawn { sender: T::AccountId, collection_id: CollectionId },
+		/// Collection offer was accepted for a token
+		CollectionOfferAccepted {
+			owner: T::AccountId,
+			buyer: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		},
+		/// Listing expired and the token was unlocked
+		ListingExpired { owner: T::AccountId, collection_id: CollectionId, nft_id: NftId },
+		/// Offer expired and the offered amount was unreserved
+		OfferExpired { offerer: T::AccountId, collection_id: CollectionId, nft_id: NftId },
+		/// Collection offer expired and the offered amount was unreserved
+		CollectionOfferExpired { offerer: T::AccountId, collection_id: CollectionId },
+		/// Swap of tokens was proposed
+		SwapProposed {
+			swap_id: SwapId,
+			proposer: T::AccountId,
+			offered: BoundedVec<(CollectionId, NftId), T::MaxNftsPerSwap>,
+			top_up: BalanceOf<T>,
+			target: SwapTarget,
+		},
+		/// Swap proposal was cancelled
+		SwapCancelled { swap_id: SwapId, sender: T::AccountId },
+		/// Swap was accepted and the tokens exchanged
+		SwapAccepted {
+			swap_id: SwapId,
+			proposer: T::AccountId,
+			acceptor: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		},
+		/// Bundle of tokens listed on Marketplace
+		BundleListed {
+			bundle_id: BundleId,
+			owner: T::AccountId,
+			nfts: BoundedVec<(CollectionId, NftId), T::MaxNftsPerBundle>,
+			price: BalanceOf<T>,
+		},
+		/// Bundle of tokens unlisted on Marketplace
+		BundleUnlisted { bundle_id: BundleId, owner: T::AccountId },
+		/// Bundle of tokens was sold to a new owner. `fee` is the total marketplace fee, the
+		/// price, fee and royalty of each token are in its `TokenSold` event.
+		BundleSold {
+			bundle_id: BundleId,
+			owner: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+		/// Bundle listing expired and the tokens were unlocked
+		BundleExpired { bundle_id: BundleId, owner: T::AccountId },
+		/// Auction was created for a token
+		AuctionCreated {
+			owner: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			reserve_price: BalanceOf<T>,
+			min_bid_increment: BalanceOf<T>,
+			end: T::BlockNumber,
+		},
+		/// Bid was placed in an auction
+		BidPlaced {
+			bidder: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			amount: BalanceOf<T>,
+		},
+		/// Auction end was extended by a late bid
+		AuctionExtended { collection_id: CollectionId, nft_id: NftId, end: T::BlockNumber },
+		/// Auction was cancelled before receiving any bid
+		AuctionCancelled { owner: T::AccountId, collection_id: CollectionId, nft_id: NftId },
+		/// Auction ended and the token was sold to the highest bidder
+		AuctionSettled {
+			owner: T::AccountId,
+			winner: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			price: BalanceOf<T>,
+			fee: BalanceOf<T>,
+			royalty: Option<(T::AccountId, BalanceOf<T>)>,
+		},
+		/// Auction ended without any bid
+		AuctionEndedWithoutBids { owner: T::AccountId, collection_id: CollectionId, nft_id: NftId },
+		/// Auction could not be settled, the highest bid was refunded and the token unlocked
+		AuctionSettlementFailed { collection_id: CollectionId, nft_id: NftId, error: DispatchError },
+		/// Auction was cancelled by the protocol and the highest bid was refunded
+		AuctionForceCancelled { owner: T::AccountId, collection_id: CollectionId, nft_id: NftId },
+		/// All listings, offers, bundles, swaps and auctions of a collection were cancelled
+		CollectionCancelled { collection_id: CollectionId },
+		/// Fraudulent listing was removed and `amount` of its deposit was slashed
+		ListingDepositSlashed {
+			owner: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			amount: BalanceOf<T>,
+		},
+		/// Marketplace was paused or unpaused
+		MarketPauseChanged { paused: bool },
+		/// Trading the tokens of a collection was paused or unpaused
+		CollectionPauseChanged { collection_id: CollectionId, paused: bool },
+		/// Token was listed for rent
+		RentalListed {
+			owner: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			price_per_block: BalanceOf<T>,
+			max_duration: T::BlockNumber,
+		},
+		/// Token was unlisted for rent
+		RentalUnlisted { owner: T::AccountId, collection_id: CollectionId, nft_id: NftId },
+		/// Token was rented until the `expires` block. `fee` and `royalty` are taken from
+		/// `price` as for a sale.
+		TokenRented {
+			owner: T::AccountId,
+			renter: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			price: BalanceOf<T>,
+			fee: BalanceOf<T>,
+			royalty: Option<(T::AccountId, BalanceOf<T>)>,
+			expires: T::BlockNumber,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -184,10 +659,127 @@ pub mod pallet {
 		OfferHasExpired,
 		/// Listing has expired and cannot be bought
 		ListingHasExpired,
-		/// Price differs from when `buy` was executed
+		/// Price differs from when `buy_bundle` was executed
 		PriceDiffersFromExpected,
 		/// Not possible to list non-transferable NFT
 		NonTransferable,
+		/// Cannot list or accept an offer on a NFT that is in an auction
+		TokenInAuction,
+		/// Cannot auction a NFT that is listed, it must be unlisted first
+		TokenIsListed,
+		/// Auction is unknown
+		UnknownAuction,
+		/// Auction end must be after the current block
+		InvalidAuctionEnd,
+		/// Auction has ended and cannot receive bids
+		AuctionHasEnded,
+		/// Cannot bid in an auction of own NFT
+		CannotBidOnOwnAuction,
+		/// Bid is below the reserve price or the minimum increment over the highest bid
+		BidTooLow,
+		/// Auction with bids cannot be cancelled
+		CannotCancelAuctionWithBids,
+		/// Too many auctions already end at the requested block
+		TooManyAuctionsEndingAtBlock,
+		/// Dutch auction end price must not exceed its start price and its duration must not be
+		/// zero
+		InvalidDutchListing,
+		/// Listing is reserved for another buyer
+		ListingReserved,
+		/// Cannot reserve a listing for its owner
+		CannotReserveForOwner,
+		/// Current price of a Dutch auction is above the maximum price of the buyer
+		PriceAboveMaximum,
+		/// Cannot make an offer on a non-existing collection
+		CollectionDoesNotExist,
+		/// Collection offer quantity must not be zero
+		InvalidQuantity,
+		/// Cannot list, auction or sell a NFT that is in a swap
+		TokenInSwap,
+		/// NFT is locked by a listing, an auction or a swap
+		TokenLocked,
+		/// Swap must offer at least one NFT
+		EmptySwap,
+		/// Swap is unknown
+		UnknownSwap,
+		/// Cannot swap a NFT for an own NFT
+		CannotSwapWithOwnToken,
+		/// NFT given to accept a swap is not the requested NFT
+		SwapTargetMismatch,
+		/// No swap id available
+		NoAvailableSwapId,
+		/// Cannot list, auction or sell a NFT that is in a bundle
+		TokenInBundle,
+		/// Bundle must contain at least one NFT
+		EmptyBundle,
+		/// Bundle is unknown
+		UnknownBundle,
+		/// No bundle id available
+		NoAvailableBundleId,
+		/// Marketplace is paused
+		MarketPaused,
+		/// Trading the NFTs of the collection is paused
+		CollectionPaused,
+		/// NFT is not listed for rent by its current owner
+		UnknownRentalListing,
+		/// Rental duration must be greater than zero and not exceed the maximum duration
+		InvalidRentalDuration,
+		/// Cannot rent an own NFT
+		CannotRentOwnToken,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
+	where
+		T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			migration::migrate_to_v1::<T>()
+		}
+
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let ending = AuctionsEndingAt::<T>::take(now);
+			let settled = ending.len() as Weight;
+			for (collection_id, nft_id) in ending {
+				Self::settle_auction(collection_id, nft_id);
+			}
+
+			T::DbWeight::get().reads_writes(1, 1).saturating_add(
+				settled.saturating_mul(10_000 + T::DbWeight::get().reads_writes(6, 6)),
+			)
+		}
+
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			let expiration_weight = 10_000 + db_weight.reads_writes(3, 4);
+			let mut consumed = db_weight.reads_writes(1, 1);
+			if consumed > remaining_weight {
+				return 0
+			}
+
+			// Clean up expired listings and offers block by block until the weight runs out
+			let mut block = NextExpirationToClean::<T>::get();
+			while block <= now && consumed.saturating_add(expiration_weight) <= remaining_weight {
+				match Expirations::<T>::iter_key_prefix(block).next() {
+					Some(expiring) => {
+						Expirations::<T>::remove(block, &expiring);
+						// A failed refund leaves the offer in place for its maker to withdraw, and
+						// it is retried at the next block
+						if Self::expire(expiring.clone(), now).is_err() {
+							Expirations::<T>::insert(now.saturating_add(1u32.into()), expiring, ());
+						}
+						consumed = consumed.saturating_add(expiration_weight);
+					},
+					None => {
+						block = block.saturating_add(1u32.into());
+						consumed = consumed.saturating_add(db_weight.reads(1));
+					},
+				}
+			}
+			NextExpirationToClean::<T>::put(block);
+
+			consumed
+		}
 	}
 
 	#[pallet::call]
@@ -202,30 +794,32 @@ pub mod pallet {
 		/// 	- `origin` - Account of the potential buyer
 		/// 	- `collection_id` - Collection id of the RMRK NFT
 		/// 	- `nft_id` - NFT id of the RMRK NFT
-		/// 	- `amount` - Optional price at which buyer purchased at
+		/// 	- `max_price` - Optional maximum price the buyer accepts to pay, the current price of
+		///   the listing is paid if it does not exceed it
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
 			collection_id: CollectionId,
 			nft_id: NftId,
-			amount: Option<BalanceOf<T>>,
+			max_price: Option<BalanceOf<T>>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			Self::do_buy(sender, collection_id, nft_id, amount, false)
+			Self::do_buy(sender, collection_id, nft_id, max_price, false)
 		}
 
 		/// List a RMRK NFT on the Marketplace for purchase. A listing can be cancelled, and is
 		/// automatically considered cancelled when a `buy` is executed on top of a given listing.
-		/// An NFT that has another NFT as its owner CANNOT be listed. An NFT owned by a NFT must
-		/// first be sent to an account before being listed.
+		/// An NFT that has another NFT as its owner can be listed by its root owner, and is
+		/// detached from its parent NFT when bought. An equipped NFT CANNOT be listed.
 		///
 		/// Parameters:
 		/// 	- `origin` - Account of owner of the RMRK NFT to be listed
 		/// 	- `collection_id` - Collection id of the RMRK NFT
 		/// 	- `nft_id` - NFT id of the RMRK NFT
 		/// 	- `amount` - Price of the RMRK NFT
+		/// 	- `asset` - Native currency or asset in which the price is paid
 		/// 	- `expires` - Optional BlockNumber for when the listing expires
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
@@ -234,49 +828,154 @@ pub mod pallet {
 			collection_id: CollectionId,
 			nft_id: NftId,
 			amount: BalanceOf<T>,
+			asset: PaymentAssetOf<T>,
 			expires: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			let owner = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
-				.ok_or(Error::<T>::TokenDoesNotExist)?;
-
-			// Ensure that the NFT is not owned by an NFT
-			ensure!(
-				!Self::is_nft_owned_by_nft(collection_id, nft_id),
-				Error::<T>::CannotListNftOwnedByNft
-			);
-			// Ensure sender is the owner
-			ensure!(sender == owner, Error::<T>::NoPermission);
 
-			let nft = pallet_rmrk_core::Pallet::<T>::nfts(collection_id, nft_id)
-				.ok_or(Error::<T>::TokenDoesNotExist)?;
+			Self::do_list(
+				sender.clone(),
+				collection_id,
+				nft_id,
+				amount,
+				asset,
+				expires,
+				ListingKind::FixedPrice,
+				None,
+			)?;
 
-			// Check NFT is transferable
-			pallet_rmrk_core::Pallet::<T>::check_is_transferable(&nft)?;
+			Self::deposit_event(Event::TokenListed {
+				owner: sender,
+				collection_id,
+				nft_id,
+				price: amount,
+				asset,
+			});
 
-			// Lock NFT to prevent transfers or interactions with the NFT
-			pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), true);
-			// Check if a prior listing is in storage from previous owner and update if found
-			if Self::is_nft_listed(collection_id, nft_id) {
-				ListedNfts::<T>::remove(collection_id, nft_id);
-			}
+			Ok(())
+		}
+
+		/// List a RMRK NFT on the Marketplace in a Dutch auction. The price of the NFT decreases
+		/// linearly from `start_price` at the `start` block to `end_price` after `duration`
+		/// blocks, and stays at `end_price` until the listing is bought, cancelled or expires.
+		/// The NFT can be bought with `buy` at its current price.
+		///
+		/// Parameters:
+		/// 	- `origin` - Account of owner of the RMRK NFT to be listed
+		/// 	- `collection_id` - Collection id of the RMRK NFT
+		/// 	- `nft_id` - NFT id of the RMRK NFT
+		/// 	- `start_price` - Price of the RMRK NFT at the `start` block
+		/// 	- `end_price` - Price of the RMRK NFT once `duration` blocks have passed
+		/// 	- `asset` - Native currency or asset in which the price is paid
+		/// 	- `start` - BlockNumber at which the price starts decreasing
+		/// 	- `duration` - Number of blocks over which the price decreases
+		/// 	- `expires` - Optional BlockNumber for when the listing expires
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn list_dutch(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			start_price: BalanceOf<T>,
+			end_price: BalanceOf<T>,
+			asset: PaymentAssetOf<T>,
+			start: T::BlockNumber,
+			duration: T::BlockNumber,
+			expires: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				end_price <= start_price && !duration.is_zero(),
+				Error::<T>::InvalidDutchListing
+			);
 
-			// Add new ListInfo with listed_by, amount, Option<BlockNumber>
-			ListedNfts::<T>::insert(
+			Self::do_list(
+				sender.clone(),
 				collection_id,
 				nft_id,
-				ListInfo { listed_by: sender, amount, expires },
-			);
+				start_price,
+				asset,
+				expires,
+				ListingKind::Dutch { end_price, start, duration },
+				None,
+			)?;
+
+			Self::deposit_event(Event::TokenListedDutch {
+				owner: sender,
+				collection_id,
+				nft_id,
+				start_price,
+				end_price,
+				asset,
+				start,
+				duration,
+			});
+
+			Ok(())
+		}
+
+		/// List a RMRK NFT on the Marketplace for purchase by a specific buyer. Only `buyer` can
+		/// buy the NFT until the `reservation_expires` block, after which anyone can buy it.
+		///
+		/// Parameters:
+		/// 	- `origin` - Account of owner of the RMRK NFT to be listed
+		/// 	- `collection_id` - Collection id of the RMRK NFT
+		/// 	- `nft_id` - NFT id of the RMRK NFT
+		/// 	- `amount` - Price of the RMRK NFT
+		/// 	- `asset` - Native currency or asset in which the price is paid
+		/// 	- `buyer` - Account the listing is reserved for
+		/// 	- `reservation_expires` - Optional BlockNumber for when the reservation ends
+		/// 	- `expires` - Optional BlockNumber for when the listing expires
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,2))]
+		#[transactional]
+		pub fn list_reserved(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			amount: BalanceOf<T>,
+			asset: PaymentAssetOf<T>,
+			buyer: T::AccountId,
+			reservation_expires: Option<T::BlockNumber>,
+			expires: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(buyer != sender, Error::<T>::CannotReserveForOwner);
+
+			Self::do_list(
+				sender.clone(),
+				collection_id,
+				nft_id,
+				amount,
+				asset,
+				expires,
+				ListingKind::FixedPrice,
+				Some((buyer.clone(), reservation_expires)),
+			)?;
 
-			Self::deposit_event(Event::TokenListed { owner, collection_id, nft_id, price: amount });
+			Self::deposit_event(Event::TokenListed {
+				owner: sender.clone(),
+				collection_id,
+				nft_id,
+				price: amount,
+				asset,
+			});
+			Self::deposit_event(Event::ListingReserved {
+				owner: sender,
+				buyer,
+				collection_id,
+				nft_id,
+				expires: reservation_expires,
+			});
 
 			Ok(())
 		}
 
-		/// Unlist a RMRK NFT on the Marketplace and remove from storage in `Listings`.
+		/// Unlist a RMRK NFT on the Marketplace and remove from storage in `Listings`. The account
+		/// that listed the NFT can still unlist it once it is no longer the owner, e.g. after the
+		/// parent of a nested NFT changed hands, to get the listing deposit back.
 		///
 		/// Parameters:
-		/// - `origin` - Account owner of the listed RMRK NFT
+		/// - `origin` - Account owner of the listed RMRK NFT, or account that listed it
 		/// - `collection_id` - Collection id of the RMRK NFT
 		/// - `nft_id` - NFT id of the RMRK NFT
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
@@ -288,17 +987,21 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			// Check if NFT is still in ListedNfts storage
-			ensure!(Self::is_nft_listed(collection_id, nft_id), Error::<T>::CannotUnlistToken);
-			let owner = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
-				.ok_or(Error::<T>::TokenDoesNotExist)?;
-			// Ensure owner of NFT is performing call to unlist
-			ensure!(sender == owner, Error::<T>::NoPermission);
+			let list_info =
+				Self::listed_nfts(collection_id, nft_id).ok_or(Error::<T>::CannotUnlistToken)?;
+			let owner = Self::root_owner(collection_id, nft_id)?;
+			// Ensure owner of NFT or the account that listed it is performing call to unlist
+			ensure!(sender == owner || sender == list_info.listed_by, Error::<T>::NoPermission);
 			// Set the NFT lock to false to allow interactions with the NFT
 			pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
 			// Remove from storage
-			ListedNfts::<T>::remove(collection_id, nft_id);
+			Self::remove_listing(collection_id, nft_id);
 			// Emit TokenUnlisted Event
-			Self::deposit_event(Event::TokenUnlisted { owner, collection_id, nft_id });
+			Self::deposit_event(Event::TokenUnlisted {
+				owner: list_info.listed_by,
+				collection_id,
+				nft_id,
+			});
 
 			Ok(())
 		}
@@ -311,22 +1014,24 @@ pub mod pallet {
 		/// - `collection_id` - Collection id of the RMRK NFT
 		/// - `nft_id` - NFT id of the RMRK NFT
 		/// - `amount` - Price of the RMRK NFT
+		/// - `asset` - Native currency or asset in which the price is paid
 		/// - `expiration` - Expiration of the offer
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,2))]
 		#[transactional]
 		pub fn make_offer(
 			origin: OriginFor<T>,
 			collection_id: CollectionId,
 			nft_id: NftId,
 			amount: BalanceOf<T>,
+			asset: PaymentAssetOf<T>,
 			expires: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
+			Self::ensure_not_paused(collection_id)?;
 			// Ensure amount is above the minimum threshold
-			ensure!(amount >= T::MinimumOfferAmount::get(), Error::<T>::OfferTooLow);
+			ensure!(amount >= Self::minimum_offer_amount(asset), Error::<T>::OfferTooLow);
 			// Ensure NFT exists & sender is not owner
-			let owner = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
-				.ok_or(Error::<T>::TokenDoesNotExist)?;
+			let owner = Self::root_owner(collection_id, nft_id)?;
 
 			ensure!(sender != owner, Error::<T>::CannotOfferOnOwnToken);
 			// If offer has already been made, must withdraw_offer first before making a new offer
@@ -336,14 +1041,19 @@ pub mod pallet {
 			);
 
 			// Reserve currency from offerer account
-			<T as pallet::Config>::Currency::reserve(&sender, amount)?;
+			Self::reserve_asset(asset, &sender, amount)?;
 
 			let token_id = (collection_id, nft_id);
 			// Insert new offer into Offers storage
 			Offers::<T>::insert(
 				token_id,
 				sender.clone(),
-				Offer { maker: sender.clone(), amount, expires },
+				Offer { maker: sender.clone(), amount, asset, expires },
+			);
+			OffersInCollection::<T>::insert(collection_id, (nft_id, sender.clone()), ());
+			Self::schedule_expiration(
+				expires,
+				Expiring::Offer(collection_id, nft_id, sender.clone()),
 			);
 
 			// Emit OfferPlaced event
@@ -352,11 +1062,63 @@ pub mod pallet {
 				collection_id,
 				nft_id,
 				price: amount,
+				asset,
 			});
 
 			Ok(())
 		}
 
+		/// Update the amount of an offer on a RMRK NFT, reserving or unreserving the difference
+		/// with the previous amount
+		///
+		/// Parameters:
+		/// - `origin` - Account that made the offer
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		/// - `amount` - New price of the RMRK NFT
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn update_offer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_not_paused(collection_id)?;
+
+			let token_id = (collection_id, nft_id);
+			Offers::<T>::try_mutate(token_id, sender.clone(), |maybe_offer| -> DispatchResult {
+				let offer = maybe_offer.as_mut().ok_or(Error::<T>::UnknownOffer)?;
+
+				if let Some(expires) = offer.expires {
+					if expires <= <frame_system::Pallet<T>>::block_number() {
+						return Err(Error::<T>::OfferHasExpired.into())
+					}
+				}
+				// Ensure amount is above the minimum threshold
+				ensure!(amount >= Self::minimum_offer_amount(offer.asset), Error::<T>::OfferTooLow);
+
+				// Reserve or unreserve the difference with the previous amount
+				if amount > offer.amount {
+					Self::reserve_asset(offer.asset, &sender, amount - offer.amount)?;
+				} else {
+					Self::unreserve_asset(offer.asset, &sender, offer.amount - amount)?;
+				}
+				offer.amount = amount;
+
+				Self::deposit_event(Event::OfferUpdated {
+					offerer: sender.clone(),
+					collection_id,
+					nft_id,
+					price: amount,
+					asset: offer.asset,
+				});
+
+				Ok(())
+			})
+		}
+
 		/// Withdraw an offer on a RMRK NFT, such that it is no longer available to be accepted by
 		/// the NFT owner
 		///
@@ -364,7 +1126,7 @@ pub mod pallet {
 		/// - `origin` - Account that wants to withdraw their offer
 		/// - `collection_id` - Collection id of the RMRK NFT
 		/// - `nft_id` - NFT id of the RMRK NFT
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,2))]
 		#[transactional]
 		pub fn withdraw_offer(
 			origin: OriginFor<T>,
@@ -381,16 +1143,17 @@ pub mod pallet {
 				|maybe_offer| -> DispatchResult {
 					let offer = maybe_offer.take().ok_or(Error::<T>::UnknownOffer)?;
 					// Ensure NFT exists & sender is not owner
-					let owner = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
-						.ok_or(Error::<T>::TokenDoesNotExist)?;
+					let owner = Self::root_owner(collection_id, nft_id)?;
 					// Cannot withdraw offer on own token
 					ensure!(
 						sender == owner || sender == offer.maker,
 						Error::<T>::CannotWithdrawOffer
 					);
 
+					OffersInCollection::<T>::remove(collection_id, (nft_id, sender.clone()));
+
 					// Unreserve currency from offerer account
-					<T as pallet::Config>::Currency::unreserve(&offer.maker, offer.amount);
+					Self::unreserve_asset(offer.asset, &offer.maker, offer.amount)?;
 					// Emit OfferWithdrawn Event
 					Self::deposit_event(Event::OfferWithdrawn { sender, collection_id, nft_id });
 
@@ -406,7 +1169,7 @@ pub mod pallet {
 		// - `collection_id` - Collection id of the RMRK NFT
 		// - `nft_id` - NFT id of the RMRK NFT
 		// - `offerer` - Account that made the offer
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,2))]
 		#[transactional]
 		pub fn accept_offer(
 			origin: OriginFor<T>,
@@ -416,8 +1179,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			// Ensure NFT exists & sender is not owner
-			let owner = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
-				.ok_or(Error::<T>::TokenDoesNotExist)?;
+			let owner = Self::root_owner(collection_id, nft_id)?;
 			// Cannot accept offer if not the owner
 			ensure!(sender == owner, Error::<T>::NoPermission);
 
@@ -434,7 +1196,9 @@ pub mod pallet {
 						}
 					}
 
-					<T as pallet::Config>::Currency::unreserve(&offer.maker, offer.amount);
+					OffersInCollection::<T>::remove(collection_id, (nft_id, offerer.clone()));
+
+					Self::unreserve_asset(offer.asset, &offer.maker, offer.amount)?;
 					Self::do_buy(offer.maker, collection_id, nft_id, None, true)?;
 					// Emit OfferAccepted event
 					Self::deposit_event(Event::OfferAccepted {
@@ -448,104 +1212,2036 @@ pub mod pallet {
 				},
 			)
 		}
-	}
-}
 
-impl<T: Config> Pallet<T>
-where
-	T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
-{
-	/// Buy the NFT helper funciton logic to handle both transactional calls of `buy` and
-	/// `accept_offer`
-	///
-	/// Parameters:
-	/// - `buyer`: The account that is buying the RMRK NFT
-	/// - `collection_id`: The collection id of the RMRK NFT
-	/// - `nft_id`: The id of the RMRK NFT
-	/// - `amount`: Optional amount at which the buyer purchased a RMRK NFT
-	/// - `is_offer`: Whether the call is from `accept_offer` or `buy`
-	fn do_buy(
-		buyer: T::AccountId,
-		collection_id: CollectionId,
-		nft_id: NftId,
-		amount: Option<BalanceOf<T>>,
-		is_offer: bool,
-	) -> DispatchResult {
-		// Ensure buyer is not the root owner
-		let owner = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
-			.ok_or(Error::<T>::TokenDoesNotExist)?;
-		ensure!(buyer != owner, Error::<T>::CannotBuyOwnToken);
+		/// Make an offer on any RMRK NFT of a collection. The offer can be accepted by the owners
+		/// of up to `quantity` NFTs of the collection, and `amount` is reserved for each of them.
+		/// An offer can be set with an expiration where the offer can no longer be accepted.
+		///
+		/// Parameters:
+		/// - `origin` - Account of the potential buyer
+		/// - `collection_id` - Collection id of the RMRK NFTs
+		/// - `amount` - Price of each RMRK NFT in the native currency
+		/// - `quantity` - Number of RMRK NFTs to buy
+		/// - `expires` - Expiration of the offer
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,1))]
+		#[transactional]
+		pub fn make_collection_offer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			amount: BalanceOf<T>,
+			quantity: u32,
+			expires: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_not_paused(collection_id)?;
+			// Ensure amount is above the minimum threshold
+			ensure!(amount >= T::MinimumOfferAmount::get(), Error::<T>::OfferTooLow);
+			ensure!(quantity > 0, Error::<T>::InvalidQuantity);
+			ensure!(
+				pallet_rmrk_core::Pallet::<T>::collections(collection_id).is_some(),
+				Error::<T>::CollectionDoesNotExist
+			);
+			// If offer has already been made, must withdraw_collection_offer first before making
+			// a new offer
+			ensure!(
+				!CollectionOffers::<T>::contains_key(collection_id, &sender),
+				Error::<T>::AlreadyOffered
+			);
 
-		let owner_origin = T::Origin::from(RawOrigin::Signed(owner.clone()));
-		let token_id = (collection_id, nft_id);
+			// Reserve currency for every NFT from offerer account
+			let reserved = amount.checked_mul(&quantity.into()).ok_or(ArithmeticError::Overflow)?;
+			<T as pallet::Config>::Currency::reserve(&sender, reserved)?;
 
-		let list_price = if is_offer {
-			Offers::<T>::get(token_id, buyer.clone())
-				.map(|o| o.amount)
-				.ok_or(Error::<T>::UnknownOffer)?
-		} else {
-			let list_info =
-				ListedNfts::<T>::take(collection_id, nft_id).ok_or(Error::<T>::TokenNotForSale)?;
-			// Ensure that the current owner is the one that listed the NFT
-			ensure!(list_info.listed_by == owner, Error::<T>::TokenNotForSale);
-			// Ensure the listing has not expired if Some(expires)
-			// if None then there is no expiration
-			if let Some(expires) = list_info.expires {
-				ensure!(
-					expires > <frame_system::Pallet<T>>::block_number(),
-					Error::<T>::ListingHasExpired
-				);
-			}
-			list_info.amount
-		};
+			CollectionOffers::<T>::insert(
+				collection_id,
+				sender.clone(),
+				CollectionOffer { maker: sender.clone(), amount, quantity, reserved, expires },
+			);
+			Self::schedule_expiration(
+				expires,
+				Expiring::CollectionOffer(collection_id, sender.clone()),
+			);
 
-		// Check if list_price is equal to amount to prevent front running a buy
-		if let Some(amount) = amount {
-			ensure!(list_price == amount, Error::<T>::PriceDiffersFromExpected);
+			Self::deposit_event(Event::CollectionOfferPlaced {
+				offerer: sender,
+				collection_id,
+				price: amount,
+				quantity,
+			});
+
+			Ok(())
 		}
 
-		// Set NFT Lock status to false to facilitate the purchase
-		pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+		/// Withdraw an offer on any RMRK NFT of a collection, such that it is no longer available
+		/// to be accepted, and unreserve the amount for the NFTs that were not bought
+		///
+		/// Parameters:
+		/// - `origin` - Account that wants to withdraw their offer
+		/// - `collection_id` - Collection id of the RMRK NFTs
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn withdraw_collection_offer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
 
-		// Transfer currency then transfer the NFT
-		<T as pallet::Config>::Currency::transfer(
-			&buyer,
-			&owner,
-			list_price,
-			ExistenceRequirement::KeepAlive,
-		)?;
+			let offer = CollectionOffers::<T>::take(collection_id, &sender)
+				.ok_or(Error::<T>::UnknownOffer)?;
 
-		let new_owner = AccountIdOrCollectionNftTuple::AccountId(buyer.clone());
-		pallet_rmrk_core::Pallet::<T>::send(owner_origin, collection_id, nft_id, new_owner)?;
+			// Unreserve currency from offerer account
+			<T as pallet::Config>::Currency::unreserve(&offer.maker, offer.reserved);
+			Self::deposit_event(Event::CollectionOfferWithdrawn { sender, collection_id });
 
-		Self::deposit_event(Event::TokenSold {
-			owner,
-			buyer,
-			collection_id,
-			nft_id,
-			price: list_price,
-		});
+			Ok(())
+		}
 
-		Ok(())
-	}
+		/// Accept an offer on any RMRK NFT of a collection, selling the RMRK NFT to the offerer
+		/// at the offered amount
+		///
+		/// Parameters:
+		/// - `origin` - Account of the current owner that is accepting the offerer's offer
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		/// - `offerer` - Account that made the offer
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,4))]
+		#[transactional]
+		pub fn accept_collection_offer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			offerer: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			// Ensure NFT exists & sender is owner
+			let owner = Self::root_owner(collection_id, nft_id)?;
+			ensure!(sender == owner, Error::<T>::NoPermission);
+			ensure!(offerer != owner, Error::<T>::CannotBuyOwnToken);
+			// Ensure the offer does not bypass an auction of the NFT
+			ensure!(!Self::is_nft_in_auction(collection_id, nft_id), Error::<T>::TokenInAuction);
+			Self::ensure_not_paused(collection_id)?;
 
-	/// Helper function to check if a RMRK NFT is listed
-	///
-	/// Parameters:
-	/// - collection_id: The collection id of the RMRK NFT
-	/// - nft_id: The nft id of the RMRK NFT
-	fn is_nft_listed(collection_id: CollectionId, nft_id: NftId) -> bool {
-		ListedNfts::<T>::contains_key(collection_id, nft_id)
-	}
+			CollectionOffers::<T>::try_mutate_exists(
+				collection_id,
+				offerer.clone(),
+				|maybe_offer| -> DispatchResult {
+					let offer = maybe_offer.as_mut().ok_or(Error::<T>::UnknownOffer)?;
 
-	/// Helper function to check if an account has already submitted an offer on a RMRK NFT
-	///
-	/// Parameters:
-	/// - collection_id: The collection id of the RMRK NFT
-	/// - nft_id: The nft id of the RMRK NFT
-	/// - sender: The account that may or may not have already sent an offer
-	fn has_active_offer(collection_id: CollectionId, nft_id: NftId, sender: T::AccountId) -> bool {
-		Offers::<T>::contains_key((collection_id, nft_id), sender)
+					if let Some(expires) = offer.expires {
+						if expires <= <frame_system::Pallet<T>>::block_number() {
+							return Err(Error::<T>::OfferHasExpired.into())
+						}
+					}
+
+					let price = offer.amount;
+					<T as pallet::Config>::Currency::unreserve(&offer.maker, price);
+					offer.reserved = offer.reserved.saturating_sub(price);
+					// Remove the offer once all NFTs have been bought
+					offer.quantity = offer.quantity.saturating_sub(1);
+					if offer.quantity == 0 {
+						*maybe_offer = None;
+					}
+
+					let (fee, royalty) = Self::do_sale(
+						owner.clone(),
+						offerer.clone(),
+						collection_id,
+						nft_id,
+						price,
+						PaymentAsset::Native,
+					)?;
+
+					Self::deposit_event(Event::TokenSold {
+						owner: owner.clone(),
+						buyer: offerer.clone(),
+						collection_id,
+						nft_id,
+						price,
+						asset: PaymentAsset::Native,
+						fee,
+						royalty,
+					});
+					Self::deposit_event(Event::CollectionOfferAccepted {
+						owner,
+						buyer: offerer,
+						collection_id,
+						nft_id,
+					});
+
+					Ok(())
+				},
+			)
+		}
+
+		/// List a bundle of RMRK NFTs on the Marketplace, to be sold together at one price. The
+		/// NFTs are locked and a `ListingDeposit` is reserved until the bundle is bought, unlisted
+		/// or expires.
+		///
+		/// Parameters:
+		/// - `origin` - Account of owner of the RMRK NFTs to be listed
+		/// - `nfts` - Collection id and NFT id of each RMRK NFT
+		/// - `amount` - Price of all RMRK NFTs in the native currency
+		/// - `expires` - Optional BlockNumber for when the listing expires
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,3).saturating_mul(
+			nfts.len() as Weight + 1
+		))]
+		#[transactional]
+		pub fn list_bundle(
+			origin: OriginFor<T>,
+			nfts: BoundedVec<(CollectionId, NftId), T::MaxNftsPerBundle>,
+			amount: BalanceOf<T>,
+			expires: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(!nfts.is_empty(), Error::<T>::EmptyBundle);
+
+			let bundle_id = Self::next_bundle_id();
+			NextBundleId::<T>::put(
+				bundle_id.checked_add(1).ok_or(Error::<T>::NoAvailableBundleId)?,
+			);
+
+			// Lock the NFTs, ensuring the sender owns them
+			for (collection_id, nft_id) in nfts.iter().copied() {
+				Self::ensure_not_paused(collection_id)?;
+				let owner = Self::root_owner(collection_id, nft_id)?;
+				ensure!(sender == owner, Error::<T>::NoPermission);
+				Self::lock_unlocked_nft(collection_id, nft_id)?;
+				NftsInBundles::<T>::insert(collection_id, nft_id, bundle_id);
+			}
+
+			// Reserve the listing deposit from the owner
+			let deposit = T::ListingDeposit::get();
+			<T as pallet::Config>::Currency::reserve(&sender, deposit)?;
+
+			Bundles::<T>::insert(
+				bundle_id,
+				BundleInfo {
+					listed_by: sender.clone(),
+					nfts: nfts.clone(),
+					amount,
+					expires,
+					deposit,
+				},
+			);
+			Self::schedule_expiration(expires, Expiring::Bundle(bundle_id));
+
+			Self::deposit_event(Event::BundleListed {
+				bundle_id,
+				owner: sender,
+				nfts,
+				price: amount,
+			});
+
+			Ok(())
+		}
+
+		/// Unlist a bundle of RMRK NFTs on the Marketplace and unlock the NFTs
+		///
+		/// Parameters:
+		/// - `origin` - Account owner of the listed RMRK NFTs
+		/// - `bundle_id` - Id of the bundle
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,2).saturating_mul(
+			T::MaxNftsPerBundle::get() as Weight + 1
+		))]
+		#[transactional]
+		pub fn unlist_bundle(origin: OriginFor<T>, bundle_id: BundleId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let bundle = Bundles::<T>::take(bundle_id).ok_or(Error::<T>::UnknownBundle)?;
+			ensure!(sender == bundle.listed_by, Error::<T>::NoPermission);
+
+			Self::release_bundle(&bundle);
+
+			Self::deposit_event(Event::BundleUnlisted { bundle_id, owner: sender });
+
+			Ok(())
+		}
+
+		/// Buy a listed bundle of RMRK NFTs, transferring all NFTs to the buyer. The price is
+		/// split evenly between the NFTs to pay the marketplace fee and the royalty of each NFT.
+		///
+		/// Parameters:
+		/// - `origin` - Account of the potential buyer
+		/// - `bundle_id` - Id of the bundle
+		/// - `amount` - Optional price at which buyer purchased at
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6,6).saturating_mul(
+			T::MaxNftsPerBundle::get() as Weight + 1
+		))]
+		#[transactional]
+		pub fn buy_bundle(
+			origin: OriginFor<T>,
+			bundle_id: BundleId,
+			amount: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let bundle = Bundles::<T>::take(bundle_id).ok_or(Error::<T>::UnknownBundle)?;
+			ensure!(sender != bundle.listed_by, Error::<T>::CannotBuyOwnToken);
+			// Ensure the listing has not expired if Some(expires)
+			if let Some(expires) = bundle.expires {
+				ensure!(
+					expires > <frame_system::Pallet<T>>::block_number(),
+					Error::<T>::ListingHasExpired
+				);
+			}
+			// Check if the price is equal to amount to prevent front running a buy
+			if let Some(amount) = amount {
+				ensure!(bundle.amount == amount, Error::<T>::PriceDiffersFromExpected);
+			}
+
+			Self::release_bundle(&bundle);
+
+			// Split the price evenly between the NFTs, the first NFT gets the remainder
+			let count: BalanceOf<T> = (bundle.nfts.len() as u32).into();
+			let share = bundle.amount / count;
+			let mut remainder = bundle.amount % count;
+			let mut total_fee = BalanceOf::<T>::zero();
+			for (collection_id, nft_id) in bundle.nfts.iter().copied() {
+				Self::ensure_not_paused(collection_id)?;
+				let price = share.saturating_add(remainder);
+				remainder = Zero::zero();
+
+				let (fee, royalty) = Self::do_sale(
+					bundle.listed_by.clone(),
+					sender.clone(),
+					collection_id,
+					nft_id,
+					price,
+					PaymentAsset::Native,
+				)?;
+				total_fee = total_fee.saturating_add(fee);
+
+				Self::deposit_event(Event::TokenSold {
+					owner: bundle.listed_by.clone(),
+					buyer: sender.clone(),
+					collection_id,
+					nft_id,
+					price,
+					asset: PaymentAsset::Native,
+					fee,
+					royalty,
+				});
+			}
+
+			Self::deposit_event(Event::BundleSold {
+				bundle_id,
+				owner: bundle.listed_by,
+				buyer: sender,
+				price: bundle.amount,
+				fee: total_fee,
+			});
+
+			Ok(())
+		}
+
+		/// Propose to swap one or more RMRK NFTs, with an optional amount of currency, for a
+		/// specific RMRK NFT or for any RMRK NFT of a collection. The offered NFTs are locked until
+		/// the swap is accepted or cancelled, while the requested NFT stays free until the swap is
+		/// accepted. Nested NFTs can be swapped by their root owner, and are detached from their
+		/// parent NFT. A `ListingDeposit` is reserved from the proposer until the swap is accepted
+		/// or cancelled.
+		///
+		/// Parameters:
+		/// - `origin` - Account of owner of the offered RMRK NFTs
+		/// - `offered` - Collection id and NFT id of each offered RMRK NFT
+		/// - `top_up` - Amount in the native currency given in addition to the offered RMRK NFTs,
+		///   can be zero
+		/// - `target` - Specific RMRK NFT or collection of the RMRK NFT requested in exchange
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,5).saturating_mul(
+			offered.len() as Weight + 1
+		))]
+		#[transactional]
+		pub fn propose_swap(
+			origin: OriginFor<T>,
+			offered: BoundedVec<(CollectionId, NftId), T::MaxNftsPerSwap>,
+			top_up: BalanceOf<T>,
+			target: SwapTarget,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(!offered.is_empty(), Error::<T>::EmptySwap);
+
+			let swap_id = Self::next_swap_id();
+			NextSwapId::<T>::put(swap_id.checked_add(1).ok_or(Error::<T>::NoAvailableSwapId)?);
+
+			// Lock the offered NFTs, ensuring the sender is their root owner
+			for (collection_id, nft_id) in offered.iter().copied() {
+				Self::ensure_not_paused(collection_id)?;
+				let owner = Self::root_owner(collection_id, nft_id)?;
+				ensure!(sender == owner, Error::<T>::NoPermission);
+				Self::lock_for_swap(swap_id, collection_id, nft_id)?;
+			}
+
+			// Ensure the requested NFT or collection exists. The requested NFT is deliberately not
+			// locked, so that proposing a swap cannot block its owner from trading it;
+			// `accept_swap` checks instead that it is not locked by anything else.
+			Self::ensure_not_paused(target.collection_id())?;
+			match target {
+				SwapTarget::Nft(collection_id, nft_id) => {
+					let owner = Self::root_owner(collection_id, nft_id)?;
+					ensure!(sender != owner, Error::<T>::CannotSwapWithOwnToken);
+				},
+				SwapTarget::Collection(collection_id) => ensure!(
+					pallet_rmrk_core::Pallet::<T>::collections(collection_id).is_some(),
+					Error::<T>::CollectionDoesNotExist
+				),
+			}
+
+			// Reserve the top up and the listing deposit from the sender account
+			let deposit = T::ListingDeposit::get();
+			<T as pallet::Config>::Currency::reserve(&sender, top_up.saturating_add(deposit))?;
+
+			Swaps::<T>::insert(
+				swap_id,
+				SwapInfo {
+					proposer: sender.clone(),
+					offered: offered.clone(),
+					top_up,
+					target,
+					deposit,
+				},
+			);
+			SwapsInCollection::<T>::insert(target.collection_id(), swap_id, ());
+
+			Self::deposit_event(Event::SwapProposed {
+				swap_id,
+				proposer: sender,
+				offered,
+				top_up,
+				target,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel a swap proposal, unlocking the offered RMRK NFTs and unreserving the top up. A
+		/// swap can be cancelled by its proposer or by the owner of the requested RMRK NFT.
+		///
+		/// Parameters:
+		/// - `origin` - Account of the proposer or of the owner of the requested RMRK NFT
+		/// - `swap_id` - Id of the swap
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,3).saturating_mul(
+			T::MaxNftsPerSwap::get() as Weight + 1
+		))]
+		#[transactional]
+		pub fn cancel_swap(origin: OriginFor<T>, swap_id: SwapId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let swap = Swaps::<T>::take(swap_id).ok_or(Error::<T>::UnknownSwap)?;
+			let is_target_owner = match swap.target {
+				SwapTarget::Nft(collection_id, nft_id) =>
+					Self::root_owner(collection_id, nft_id).map_or(false, |owner| owner == sender),
+				SwapTarget::Collection(_) => false,
+			};
+			ensure!(sender == swap.proposer || is_target_owner, Error::<T>::NoPermission);
+
+			Self::release_swap(swap_id, &swap);
+
+			Self::deposit_event(Event::SwapCancelled { swap_id, sender });
+
+			Ok(())
+		}
+
+		/// Accept a swap proposal, giving the requested RMRK NFT to the proposer in exchange for
+		/// the offered RMRK NFTs and top up
+		///
+		/// Parameters:
+		/// - `origin` - Account of owner of the requested RMRK NFT
+		/// - `swap_id` - Id of the swap
+		/// - `collection_id` - Collection id of the RMRK NFT given in exchange
+		/// - `nft_id` - NFT id of the RMRK NFT given in exchange
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4,5).saturating_mul(
+			T::MaxNftsPerSwap::get() as Weight + 1
+		))]
+		#[transactional]
+		pub fn accept_swap(
+			origin: OriginFor<T>,
+			swap_id: SwapId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let swap = Swaps::<T>::take(swap_id).ok_or(Error::<T>::UnknownSwap)?;
+			Self::ensure_not_paused(collection_id)?;
+			for (offered_collection_id, _) in swap.offered.iter() {
+				Self::ensure_not_paused(*offered_collection_id)?;
+			}
+			let owner = Self::root_owner(collection_id, nft_id)?;
+			ensure!(sender == owner, Error::<T>::NoPermission);
+			ensure!(sender != swap.proposer, Error::<T>::CannotSwapWithOwnToken);
+
+			// Ensure the NFT is the requested one
+			match swap.target {
+				SwapTarget::Nft(target_collection_id, target_nft_id) => ensure!(
+					(collection_id, nft_id) == (target_collection_id, target_nft_id),
+					Error::<T>::SwapTargetMismatch
+				),
+				SwapTarget::Collection(target_collection_id) =>
+					ensure!(collection_id == target_collection_id, Error::<T>::SwapTargetMismatch),
+			}
+			// Ensure the NFT is not listed, auctioned or in another swap
+			ensure!(
+				!pallet_rmrk_core::Pallet::<T>::lock((collection_id, nft_id)),
+				Error::<T>::TokenLocked
+			);
+
+			Self::release_swap(swap_id, &swap);
+
+			// Transfer the top up and the offered NFTs to the sender, then the requested NFT to
+			// the proposer
+			<T as pallet::Config>::Currency::transfer(
+				&swap.proposer,
+				&sender,
+				swap.top_up,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			let proposer_origin = T::Origin::from(RawOrigin::Signed(swap.proposer.clone()));
+			for (offered_collection_id, offered_nft_id) in swap.offered.iter().copied() {
+				pallet_rmrk_core::Pallet::<T>::send(
+					proposer_origin.clone(),
+					offered_collection_id,
+					offered_nft_id,
+					AccountIdOrCollectionNftTuple::AccountId(sender.clone()),
+				)?;
+			}
+			pallet_rmrk_core::Pallet::<T>::send(
+				T::Origin::from(RawOrigin::Signed(sender.clone())),
+				collection_id,
+				nft_id,
+				AccountIdOrCollectionNftTuple::AccountId(swap.proposer.clone()),
+			)?;
+
+			Self::deposit_event(Event::SwapAccepted {
+				swap_id,
+				proposer: swap.proposer,
+				acceptor: sender,
+				collection_id,
+				nft_id,
+			});
+
+			Ok(())
+		}
+
+		/// Create an English auction for a RMRK NFT. Bids are reserved from the bidders and the
+		/// auction is settled at the `end` block, selling the NFT to the highest bidder. The NFT
+		/// is locked until the auction is settled or cancelled.
+		///
+		/// Parameters:
+		/// - `origin` - Account of owner of the RMRK NFT to be auctioned
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		/// - `reserve_price` - Minimum amount of the first bid, bids are in the native currency
+		/// - `min_bid_increment` - Minimum amount a bid must exceed the highest bid by
+		/// - `end` - BlockNumber at which the auction is settled
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,3))]
+		#[transactional]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			reserve_price: BalanceOf<T>,
+			min_bid_increment: BalanceOf<T>,
+			end: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_not_paused(collection_id)?;
+			let owner = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
+				.ok_or(Error::<T>::TokenDoesNotExist)?;
+
+			// Ensure that the NFT is not owned by an NFT
+			ensure!(
+				!Self::is_nft_owned_by_nft(collection_id, nft_id),
+				Error::<T>::CannotListNftOwnedByNft
+			);
+			// Ensure sender is the owner
+			ensure!(sender == owner, Error::<T>::NoPermission);
+			// Ensure the NFT is neither listed, in a swap, in a bundle nor already in an auction
+			ensure!(!Self::is_nft_listed(collection_id, nft_id), Error::<T>::TokenIsListed);
+			ensure!(!Self::is_nft_in_swap(collection_id, nft_id), Error::<T>::TokenInSwap);
+			ensure!(!Self::is_nft_in_bundle(collection_id, nft_id), Error::<T>::TokenInBundle);
+			ensure!(!Self::is_nft_in_auction(collection_id, nft_id), Error::<T>::TokenInAuction);
+			ensure!(end > <frame_system::Pallet<T>>::block_number(), Error::<T>::InvalidAuctionEnd);
+
+			let nft = pallet_rmrk_core::Pallet::<T>::nfts(collection_id, nft_id)
+				.ok_or(Error::<T>::TokenDoesNotExist)?;
+
+			// Check NFT is transferable
+			pallet_rmrk_core::Pallet::<T>::check_is_transferable(&nft)?;
+
+			// Lock NFT to prevent transfers or interactions with the NFT
+			pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), true);
+
+			Self::schedule_auction_end(collection_id, nft_id, end)?;
+			Auctions::<T>::insert(
+				collection_id,
+				nft_id,
+				AuctionInfo {
+					seller: sender,
+					reserve_price,
+					min_bid_increment,
+					highest_bid: None,
+					end,
+				},
+			);
+
+			Self::deposit_event(Event::AuctionCreated {
+				owner,
+				collection_id,
+				nft_id,
+				reserve_price,
+				min_bid_increment,
+				end,
+			});
+
+			Ok(())
+		}
+
+		/// Bid in the auction of a RMRK NFT. The bid amount is reserved from the bidder and the
+		/// previous highest bidder is refunded. A bid placed less than `AuctionExtendPeriod`
+		/// blocks before the end of the auction extends the auction, to the first block from
+		/// `AuctionExtendPeriod` blocks later on where fewer than `MaxAuctionsEndingPerBlock`
+		/// auctions end.
+		///
+		/// Parameters:
+		/// - `origin` - Account of the bidder
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		/// - `amount` - Amount of the bid
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(4, 4)
+				+ T::DbWeight::get().reads(
+					UniqueSaturatedInto::<u64>::unique_saturated_into(
+						T::AuctionExtendPeriod::get()
+					)
+				)
+		)]
+		#[transactional]
+		pub fn bid(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_not_paused(collection_id)?;
+
+			Auctions::<T>::try_mutate(collection_id, nft_id, |maybe_auction| -> DispatchResult {
+				let auction = maybe_auction.as_mut().ok_or(Error::<T>::UnknownAuction)?;
+				let now = <frame_system::Pallet<T>>::block_number();
+
+				ensure!(auction.end > now, Error::<T>::AuctionHasEnded);
+				ensure!(sender != auction.seller, Error::<T>::CannotBidOnOwnAuction);
+				// Ensure the bid is above the reserve price and outbids the highest bid
+				ensure!(amount >= auction.reserve_price, Error::<T>::BidTooLow);
+				if let Some((_, highest_amount)) = &auction.highest_bid {
+					ensure!(
+						amount > *highest_amount &&
+							amount >= highest_amount.saturating_add(auction.min_bid_increment),
+						Error::<T>::BidTooLow
+					);
+				}
+
+				// Reserve currency from the bidder then refund the outbid bidder
+				<T as pallet::Config>::Currency::reserve(&sender, amount)?;
+				if let Some((previous_bidder, previous_amount)) =
+					auction.highest_bid.replace((sender.clone(), amount))
+				{
+					<T as pallet::Config>::Currency::unreserve(&previous_bidder, previous_amount);
+				}
+
+				// Extend the auction if the bid was placed close to its end
+				let extended_end = now.saturating_add(T::AuctionExtendPeriod::get());
+				if extended_end > auction.end {
+					Self::unschedule_auction_end(collection_id, nft_id, auction.end);
+					let extended_end =
+						Self::schedule_extended_auction_end(collection_id, nft_id, extended_end)?;
+					auction.end = extended_end;
+
+					Self::deposit_event(Event::AuctionExtended {
+						collection_id,
+						nft_id,
+						end: extended_end,
+					});
+				}
+
+				Self::deposit_event(Event::BidPlaced {
+					bidder: sender.clone(),
+					collection_id,
+					nft_id,
+					amount,
+				});
+
+				Ok(())
+			})
+		}
+
+		/// Cancel the auction of a RMRK NFT that has not received any bid yet.
+		///
+		/// Parameters:
+		/// - `origin` - Account of the owner that created the auction
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,3))]
+		#[transactional]
+		pub fn cancel_auction(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let auction =
+				Auctions::<T>::get(collection_id, nft_id).ok_or(Error::<T>::UnknownAuction)?;
+			ensure!(sender == auction.seller, Error::<T>::NoPermission);
+			ensure!(auction.highest_bid.is_none(), Error::<T>::CannotCancelAuctionWithBids);
+
+			// Set the NFT lock to false to allow interactions with the NFT
+			pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+			Self::unschedule_auction_end(collection_id, nft_id, auction.end);
+			Auctions::<T>::remove(collection_id, nft_id);
+
+			Self::deposit_event(Event::AuctionCancelled { owner: sender, collection_id, nft_id });
+
+			Ok(())
+		}
+
+		/// Unlist a RMRK NFT on the Marketplace on behalf of its owner. Can only be called by the
+		/// `ProtocolOrigin`.
+		///
+		/// Parameters:
+		/// - `origin` - The `ProtocolOrigin`
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,2))]
+		#[transactional]
+		pub fn force_unlist(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+
+			ensure!(Self::cancel_listing(collection_id, nft_id), Error::<T>::CannotUnlistToken);
+
+			Ok(())
+		}
+
+		/// Withdraw an offer on a RMRK NFT on behalf of the offerer, unreserving the offered
+		/// amount. Can only be called by the `ProtocolOrigin`.
+		///
+		/// Parameters:
+		/// - `origin` - The `ProtocolOrigin`
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		/// - `offerer` - Account that made the offer
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn force_withdraw_offer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			offerer: T::AccountId,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+
+			ensure!(Self::cancel_offer(collection_id, nft_id, offerer)?, Error::<T>::UnknownOffer);
+
+			Ok(())
+		}
+
+		/// Cancel all listings, offers, collection offers, bundles, swaps and auctions involving
+		/// the RMRK NFTs of a collection, unlocking the NFTs and refunding all reserved amounts.
+		/// Can only be called by the `ProtocolOrigin`, which should pause the collection first so
+		/// that no new listing or offer is made. At most `limit` of them are cancelled per call,
+		/// and `CollectionCancelled` is emitted by the call that leaves nothing to cancel.
+		///
+		/// Parameters:
+		/// - `origin` - The `ProtocolOrigin`
+		/// - `collection_id` - Collection id of the RMRK NFTs
+		/// - `limit` - Maximum number of listings, offers, bundles, swaps and auctions to cancel
+		#[pallet::weight(10_000 + Pallet::<T>::force_cancel_weight_per_item().saturating_mul(
+			*limit as Weight
+		))]
+		#[transactional]
+		pub fn force_cancel_all_for_collection(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+
+			let mut remaining = limit as usize;
+
+			// Unlist the listed NFTs
+			let listed: Vec<NftId> =
+				ListedNfts::<T>::iter_key_prefix(collection_id).take(remaining).collect();
+			remaining -= listed.len();
+			for nft_id in listed {
+				Self::cancel_listing(collection_id, nft_id);
+			}
+
+			// Withdraw the offers on the NFTs
+			let offers: Vec<(NftId, T::AccountId)> =
+				OffersInCollection::<T>::iter_key_prefix(collection_id)
+					.take(remaining)
+					.collect();
+			remaining -= offers.len();
+			for (nft_id, offerer) in offers {
+				Self::cancel_offer(collection_id, nft_id, offerer)?;
+			}
+
+			// Withdraw the offers on any NFT of the collection
+			let collection_offers: Vec<_> =
+				CollectionOffers::<T>::drain_prefix(collection_id).take(remaining).collect();
+			remaining -= collection_offers.len();
+			for (offerer, offer) in collection_offers {
+				<T as pallet::Config>::Currency::unreserve(&offer.maker, offer.reserved);
+				Self::deposit_event(Event::CollectionOfferWithdrawn {
+					sender: offerer,
+					collection_id,
+				});
+			}
+
+			// Unlist the bundles containing a NFT of the collection
+			while remaining > 0 {
+				let (nft_id, bundle_id) =
+					match NftsInBundles::<T>::iter_prefix(collection_id).next() {
+						Some(entry) => entry,
+						None => break,
+					};
+				remaining -= 1;
+				match Bundles::<T>::take(bundle_id) {
+					Some(bundle) => {
+						Self::release_bundle(&bundle);
+						Self::deposit_event(Event::BundleUnlisted {
+							bundle_id,
+							owner: bundle.listed_by,
+						});
+					},
+					None => NftsInBundles::<T>::remove(collection_id, nft_id),
+				}
+			}
+
+			// Cancel the swaps offering or requesting a NFT of the collection
+			let swap_ids: Vec<SwapId> =
+				SwapsInCollection::<T>::iter_key_prefix(collection_id).take(remaining).collect();
+			remaining -= swap_ids.len();
+			for swap_id in swap_ids {
+				match Swaps::<T>::take(swap_id) {
+					Some(swap) => {
+						Self::release_swap(swap_id, &swap);
+						Self::deposit_event(Event::SwapCancelled {
+							swap_id,
+							sender: swap.proposer,
+						});
+					},
+					None => SwapsInCollection::<T>::remove(collection_id, swap_id),
+				}
+			}
+
+			// Unlist the NFTs listed for rent, leaving the running rentals untouched
+			let rentals: Vec<_> =
+				RentalListings::<T>::drain_prefix(collection_id).take(remaining).collect();
+			remaining -= rentals.len();
+			for (nft_id, rental) in rentals {
+				<T as pallet::Config>::Currency::unreserve(&rental.listed_by, rental.deposit);
+				Self::deposit_event(Event::RentalUnlisted {
+					owner: rental.listed_by,
+					collection_id,
+					nft_id,
+				});
+			}
+
+			// Cancel the auctions, refunding the highest bid
+			let auctions: Vec<_> =
+				Auctions::<T>::drain_prefix(collection_id).take(remaining).collect();
+			remaining -= auctions.len();
+			for (nft_id, auction) in auctions {
+				if let Some((bidder, amount)) = auction.highest_bid {
+					<T as pallet::Config>::Currency::unreserve(&bidder, amount);
+				}
+				pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+				Self::unschedule_auction_end(collection_id, nft_id, auction.end);
+				Self::deposit_event(Event::AuctionForceCancelled {
+					owner: auction.seller,
+					collection_id,
+					nft_id,
+				});
+			}
+
+			// Everything was cancelled if the limit was not reached
+			if remaining > 0 {
+				Self::deposit_event(Event::CollectionCancelled { collection_id });
+			}
+
+			let cancelled = (limit as usize - remaining) as Weight;
+			Ok(Some(10_000 + Self::force_cancel_weight_per_item().saturating_mul(cancelled)).into())
+		}
+
+		/// Remove a listing flagged as fraudulent, slashing `ListingDepositSlash` of its deposit
+		/// and returning the rest to the owner. Can only be called by the `ProtocolOrigin`.
+		///
+		/// Parameters:
+		/// - `origin` - The `ProtocolOrigin`
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,3))]
+		#[transactional]
+		pub fn slash_listing(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+
+			ListedNfts::<T>::try_mutate(
+				collection_id,
+				nft_id,
+				|maybe_list_info| -> DispatchResult {
+					let list_info =
+						maybe_list_info.as_mut().ok_or(Error::<T>::CannotUnlistToken)?;
+					// Slash part of the deposit, the rest is returned when the listing is removed
+					let slash = T::ListingDepositSlash::get() * list_info.deposit;
+					let (imbalance, _) = <T as pallet::Config>::Currency::slash_reserved(
+						&list_info.listed_by,
+						slash,
+					);
+					list_info.deposit = list_info.deposit.saturating_sub(slash);
+
+					Self::deposit_event(Event::ListingDepositSlashed {
+						owner: list_info.listed_by.clone(),
+						collection_id,
+						nft_id,
+						amount: imbalance.peek(),
+					});
+					T::Slashed::on_unbalanced(imbalance);
+
+					Ok(())
+				},
+			)?;
+			Self::cancel_listing(collection_id, nft_id);
+
+			Ok(())
+		}
+
+		/// Pause or unpause the whole Marketplace. While paused, nothing can be listed, offered,
+		/// bought, swapped or auctioned, but listings, offers, bundles, swaps and auctions
+		/// without bids can still be cancelled, and ending auctions are still settled. Can only be
+		/// called by the `ProtocolOrigin`.
+		///
+		/// Parameters:
+		/// - `origin` - The `ProtocolOrigin`
+		/// - `paused` - Whether the Marketplace is paused
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn set_market_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+
+			MarketPaused::<T>::put(paused);
+			Self::deposit_event(Event::MarketPauseChanged { paused });
+
+			Ok(())
+		}
+
+		/// Pause or unpause trading the RMRK NFTs of a collection on the Marketplace, in the
+		/// same way `set_market_paused` pauses the whole Marketplace. Can only be called by the
+		/// `ProtocolOrigin`.
+		///
+		/// Parameters:
+		/// - `origin` - The `ProtocolOrigin`
+		/// - `collection_id` - Collection id of the RMRK NFTs
+		/// - `paused` - Whether trading the RMRK NFTs of the collection is paused
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn set_collection_paused(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			paused: bool,
+		) -> DispatchResult {
+			T::ProtocolOrigin::ensure_origin(origin)?;
+
+			if paused {
+				PausedCollections::<T>::insert(collection_id, ());
+			} else {
+				PausedCollections::<T>::remove(collection_id);
+			}
+			Self::deposit_event(Event::CollectionPauseChanged { collection_id, paused });
+
+			Ok(())
+		}
+
+		/// List a RMRK NFT for rent. A renter pays `price_per_block` for each block they rent the
+		/// NFT for and becomes its user until the rental ends: they can equip it, but only the
+		/// owner can transfer or burn it. The listing stays until it is unlisted, so the NFT can
+		/// be rented again once a rental ends. A `ListingDeposit` is reserved from the owner until
+		/// the NFT is unlisted, listing it again replaces the previous listing and its deposit.
+		///
+		/// Parameters:
+		/// - `origin` - Account of owner of the RMRK NFT to be listed for rent
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		/// - `price_per_block` - Price paid in the native currency for each rented block
+		/// - `max_duration` - Maximum number of blocks the RMRK NFT can be rented for at once
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5,3))]
+		#[transactional]
+		pub fn list_rental(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			price_per_block: BalanceOf<T>,
+			max_duration: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::ensure_not_paused(collection_id)?;
+			let owner = Self::root_owner(collection_id, nft_id)?;
+			ensure!(sender == owner, Error::<T>::NoPermission);
+			ensure!(!max_duration.is_zero(), Error::<T>::InvalidRentalDuration);
+
+			// Return the deposit of the previous listing, then reserve the listing deposit
+			if let Some(previous) = RentalListings::<T>::get(collection_id, nft_id) {
+				<T as pallet::Config>::Currency::unreserve(&previous.listed_by, previous.deposit);
+			}
+			let deposit = T::ListingDeposit::get();
+			<T as pallet::Config>::Currency::reserve(&sender, deposit)?;
+
+			RentalListings::<T>::insert(
+				collection_id,
+				nft_id,
+				RentalInfo { listed_by: sender.clone(), price_per_block, max_duration, deposit },
+			);
+
+			Self::deposit_event(Event::RentalListed {
+				owner: sender,
+				collection_id,
+				nft_id,
+				price_per_block,
+				max_duration,
+			});
+
+			Ok(())
+		}
+
+		/// Unlist a RMRK NFT listed for rent and return its deposit. A running rental is not
+		/// affected. Can be called by the account that listed the RMRK NFT or by its current owner.
+		///
+		/// Parameters:
+		/// - `origin` - Account of the lister or owner of the RMRK NFT
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3,2))]
+		#[transactional]
+		pub fn unlist_rental(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let rental = RentalListings::<T>::get(collection_id, nft_id)
+				.ok_or(Error::<T>::UnknownRentalListing)?;
+			ensure!(
+				sender == rental.listed_by ||
+					Self::root_owner(collection_id, nft_id)
+						.map_or(false, |owner| owner == sender),
+				Error::<T>::NoPermission
+			);
+			RentalListings::<T>::remove(collection_id, nft_id);
+			<T as pallet::Config>::Currency::unreserve(&rental.listed_by, rental.deposit);
+
+			Self::deposit_event(Event::RentalUnlisted {
+				owner: rental.listed_by,
+				collection_id,
+				nft_id,
+			});
+
+			Ok(())
+		}
+
+		/// Rent a RMRK NFT listed for rent for `duration` blocks. The renter pays the price per
+		/// block times the duration, with the marketplace fee and royalty taken as for a sale,
+		/// and becomes the user of the RMRK NFT until the rental ends. The RMRK NFT cannot be
+		/// rented while another rental of it is running.
+		///
+		/// Parameters:
+		/// - `origin` - Account of the renter
+		/// - `collection_id` - Collection id of the RMRK NFT
+		/// - `nft_id` - NFT id of the RMRK NFT
+		/// - `duration` - Number of blocks the RMRK NFT is rented for
+		/// - `max_price_per_block` - Optional maximum price per block the renter accepts to pay
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6,5))]
+		#[transactional]
+		pub fn rent(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			duration: T::BlockNumber,
+			max_price_per_block: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let renter = ensure_signed(origin)?;
+
+			Self::ensure_not_paused(collection_id)?;
+			let rental = RentalListings::<T>::get(collection_id, nft_id)
+				.ok_or(Error::<T>::UnknownRentalListing)?;
+			// Ensure that the current owner is the one that listed the NFT for rent
+			let owner = Self::root_owner(collection_id, nft_id)?;
+			ensure!(owner == rental.listed_by, Error::<T>::UnknownRentalListing);
+			ensure!(renter != owner, Error::<T>::CannotRentOwnToken);
+			ensure!(
+				!duration.is_zero() && duration <= rental.max_duration,
+				Error::<T>::InvalidRentalDuration
+			);
+			// Check the price per block to prevent front running a rent
+			if let Some(max_price_per_block) = max_price_per_block {
+				ensure!(
+					rental.price_per_block <= max_price_per_block,
+					Error::<T>::PriceAboveMaximum
+				);
+			}
+
+			let blocks: u32 = duration.unique_saturated_into();
+			let price = rental.price_per_block.saturating_mul(blocks.into());
+			let (fee, royalty) = Self::pay_owner(
+				&owner,
+				&renter,
+				collection_id,
+				nft_id,
+				price,
+				PaymentAsset::Native,
+			)?;
+
+			let expires = <frame_system::Pallet<T>>::block_number().saturating_add(duration);
+			pallet_rmrk_core::Pallet::<T>::user_set(
+				collection_id,
+				nft_id,
+				renter.clone(),
+				expires,
+			)?;
+
+			Self::deposit_event(Event::TokenRented {
+				owner,
+				renter,
+				collection_id,
+				nft_id,
+				price,
+				fee,
+				royalty,
+				expires,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T>
+where
+	T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+{
+	/// Get the price a listed RMRK NFT can currently be bought at
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	pub fn listing_price(collection_id: CollectionId, nft_id: NftId) -> Option<BalanceOf<T>> {
+		ListedNfts::<T>::get(collection_id, nft_id).map(|list_info| Self::current_price(&list_info))
+	}
+
+	/// Get the RMRK NFTs whose listing is currently reserved for an account, and the price they
+	/// can be bought at
+	///
+	/// Parameters:
+	/// - `account`: The account the listings are reserved for
+	pub fn reserved_listings(account: &T::AccountId) -> Vec<(CollectionId, NftId, BalanceOf<T>)> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		ReservedListings::<T>::iter_key_prefix(account)
+			.filter_map(|(collection_id, nft_id)| {
+				let list_info = ListedNfts::<T>::get(collection_id, nft_id)?;
+				if list_info.expires.map_or(false, |expires| expires <= now) ||
+					Self::active_reservation(&list_info) != Some(account)
+				{
+					return None
+				}
+				Some((collection_id, nft_id, Self::current_price(&list_info)))
+			})
+			.collect()
+	}
+
+	/// Get the lowest price the RMRK NFTs of a collection listed in the native currency can
+	/// currently be bought at by anyone
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFTs
+	pub fn collection_floor_price(collection_id: CollectionId) -> Option<BalanceOf<T>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		ListedNfts::<T>::iter_prefix(collection_id)
+			.filter(|(nft_id, list_info)| {
+				matches!(list_info.asset, PaymentAsset::Native) &&
+					!list_info.expires.map_or(false, |expires| expires <= now) &&
+					Self::active_reservation(list_info).is_none() &&
+					Self::root_owner(collection_id, *nft_id)
+						.map_or(false, |owner| owner == list_info.listed_by)
+			})
+			.map(|(_, list_info)| Self::current_price(&list_info))
+			.min()
+	}
+
+	/// List the NFT helper function logic to handle both fixed price and Dutch auction listings
+	///
+	/// Parameters:
+	/// - `sender`: The account listing the RMRK NFT
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The id of the RMRK NFT
+	/// - `amount`: The price of the RMRK NFT, or the start price of a Dutch auction
+	/// - `asset`: The native currency or asset in which the price is paid
+	/// - `expires`: Optional BlockNumber for when the listing expires
+	/// - `kind`: How the price of the listing is determined
+	/// - `reservation`: Optional buyer the listing is reserved for, and when the reservation ends
+	fn do_list(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		amount: BalanceOf<T>,
+		asset: PaymentAssetOf<T>,
+		expires: Option<T::BlockNumber>,
+		kind: ListingKind<BalanceOf<T>, T::BlockNumber>,
+		reservation: Option<(T::AccountId, Option<T::BlockNumber>)>,
+	) -> DispatchResult {
+		Self::ensure_not_paused(collection_id)?;
+		// A nested NFT can be listed by its root owner, and is detached from its parent on sale
+		let owner = Self::root_owner(collection_id, nft_id)?;
+		// Ensure sender is the owner
+		ensure!(sender == owner, Error::<T>::NoPermission);
+		// Ensure the NFT is neither in an auction, in a swap nor in a bundle
+		ensure!(!Self::is_nft_in_auction(collection_id, nft_id), Error::<T>::TokenInAuction);
+		ensure!(!Self::is_nft_in_swap(collection_id, nft_id), Error::<T>::TokenInSwap);
+		ensure!(!Self::is_nft_in_bundle(collection_id, nft_id), Error::<T>::TokenInBundle);
+
+		let nft = pallet_rmrk_core::Pallet::<T>::nfts(collection_id, nft_id)
+			.ok_or(Error::<T>::TokenDoesNotExist)?;
+
+		// Check NFT is transferable and is not equipped, as it could not be sent to the buyer
+		pallet_rmrk_core::Pallet::<T>::check_is_transferable(&nft)?;
+		pallet_rmrk_core::Pallet::<T>::check_is_not_equipped(&nft)?;
+
+		// Lock NFT to prevent transfers or interactions with the NFT
+		pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), true);
+		// Check if a prior listing is in storage from previous owner and update if found
+		if Self::is_nft_listed(collection_id, nft_id) {
+			Self::remove_listing(collection_id, nft_id);
+		}
+
+		// Reserve the listing deposit from the owner
+		let deposit = T::ListingDeposit::get();
+		<T as pallet::Config>::Currency::reserve(&sender, deposit)?;
+
+		let (reserved_for, reservation_expires) = match reservation {
+			Some((buyer, reservation_expires)) => {
+				ReservedListings::<T>::insert(&buyer, (collection_id, nft_id), ());
+				(Some(buyer), reservation_expires)
+			},
+			None => (None, None),
+		};
+
+		// Add new ListInfo with listed_by, amount, asset, Option<BlockNumber>, kind, the
+		// optional reservation and the deposit
+		ListedNfts::<T>::insert(
+			collection_id,
+			nft_id,
+			ListInfo {
+				listed_by: sender,
+				amount,
+				asset,
+				expires,
+				kind,
+				reserved_for,
+				reservation_expires,
+				deposit,
+			},
+		);
+		Self::schedule_expiration(expires, Expiring::Listing(collection_id, nft_id));
+
+		Ok(())
+	}
+
+	/// Buy the NFT helper funciton logic to handle both transactional calls of `buy` and
+	/// `accept_offer`
+	///
+	/// Parameters:
+	/// - `buyer`: The account that is buying the RMRK NFT
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The id of the RMRK NFT
+	/// - `max_price`: Optional maximum price the buyer accepts to pay for a RMRK NFT
+	/// - `is_offer`: Whether the call is from `accept_offer` or `buy`
+	fn do_buy(
+		buyer: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		max_price: Option<BalanceOf<T>>,
+		is_offer: bool,
+	) -> DispatchResult {
+		Self::ensure_not_paused(collection_id)?;
+		// Ensure buyer is not the root owner
+		let owner = Self::root_owner(collection_id, nft_id)?;
+		ensure!(buyer != owner, Error::<T>::CannotBuyOwnToken);
+
+		let token_id = (collection_id, nft_id);
+
+		let (list_price, asset) = if is_offer {
+			// Ensure the offer does not bypass an auction of the NFT
+			ensure!(!Self::is_nft_in_auction(collection_id, nft_id), Error::<T>::TokenInAuction);
+
+			let offer =
+				Offers::<T>::get(token_id, buyer.clone()).ok_or(Error::<T>::UnknownOffer)?;
+			(offer.amount, offer.asset)
+		} else {
+			let list_info =
+				Self::remove_listing(collection_id, nft_id).ok_or(Error::<T>::TokenNotForSale)?;
+			// Ensure that the current owner is the one that listed the NFT
+			ensure!(list_info.listed_by == owner, Error::<T>::TokenNotForSale);
+			// Ensure the listing has not expired if Some(expires)
+			// if None then there is no expiration
+			if let Some(expires) = list_info.expires {
+				ensure!(
+					expires > <frame_system::Pallet<T>>::block_number(),
+					Error::<T>::ListingHasExpired
+				);
+			}
+			// Ensure only the buyer the listing is reserved for can buy it while the
+			// reservation holds
+			if let Some(reserved_for) = Self::active_reservation(&list_info) {
+				ensure!(buyer == *reserved_for, Error::<T>::ListingReserved);
+			}
+			(Self::current_price(&list_info), list_info.asset)
+		};
+
+		// Check that list_price does not exceed the maximum price to prevent front running a buy
+		if let Some(max_price) = max_price {
+			ensure!(list_price <= max_price, Error::<T>::PriceAboveMaximum);
+		}
+
+		let (fee, royalty) =
+			Self::do_sale(owner.clone(), buyer.clone(), collection_id, nft_id, list_price, asset)?;
+
+		Self::deposit_event(Event::TokenSold {
+			owner,
+			buyer,
+			collection_id,
+			nft_id,
+			price: list_price,
+			asset,
+			fee,
+			royalty,
+		});
+
+		Ok(())
+	}
+
+	/// Helper function to pay for a RMRK NFT and transfer it to the buyer. The marketplace fee
+	/// is deducted from the price first, then if the NFT has a royalty set, the royalty
+	/// recipient is paid their cut of the remaining amount and the owner receives the rest. The
+	/// callers check that the collection is not paused, so that auctions ending during a pause
+	/// are still settled.
+	///
+	/// Parameters:
+	/// - `owner`: The account that is selling the RMRK NFT
+	/// - `buyer`: The account that is buying the RMRK NFT
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The id of the RMRK NFT
+	/// - `price`: The amount paid by the buyer
+	/// - `asset`: The native currency or asset in which the price is paid
+	///
+	/// Output:
+	/// - The marketplace fee paid to the `MarketplaceFeeRecipient`
+	/// - The royalty recipient and the amount they were paid, if the NFT has a royalty
+	fn do_sale(
+		owner: T::AccountId,
+		buyer: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		price: BalanceOf<T>,
+		asset: PaymentAssetOf<T>,
+	) -> Result<(BalanceOf<T>, Option<(T::AccountId, BalanceOf<T>)>), DispatchError> {
+		let owner_origin = T::Origin::from(RawOrigin::Signed(owner.clone()));
+
+		// Ensure the sale does not bypass a swap or a bundle of the NFT
+		ensure!(!Self::is_nft_in_swap(collection_id, nft_id), Error::<T>::TokenInSwap);
+		ensure!(!Self::is_nft_in_bundle(collection_id, nft_id), Error::<T>::TokenInBundle);
+		// Remove the listing of a NFT sold through an offer, returning its deposit
+		Self::remove_listing(collection_id, nft_id);
+
+		// Set NFT Lock status to false to facilitate the purchase
+		pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+
+		// Pay the fee, the royalty and the owner, then transfer the NFT
+		let (fee, royalty) = Self::pay_owner(&owner, &buyer, collection_id, nft_id, price, asset)?;
+
+		let new_owner = AccountIdOrCollectionNftTuple::AccountId(buyer.clone());
+		pallet_rmrk_core::Pallet::<T>::send(owner_origin, collection_id, nft_id, new_owner)?;
+
+		Self::record_sale(owner, buyer, collection_id, nft_id, price, asset);
+
+		Ok((fee, royalty))
+	}
+
+	/// Helper function to pay for a sale or a rental of a RMRK NFT. The marketplace fee is
+	/// deducted from the price first, then if the NFT has a royalty set, the royalty recipient
+	/// is paid their cut of the remaining amount and the owner receives the rest.
+	///
+	/// Parameters:
+	/// - `owner`: The account that is paid for the RMRK NFT
+	/// - `payer`: The account that pays the price
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The id of the RMRK NFT
+	/// - `price`: The amount paid by the payer
+	/// - `asset`: The native currency or asset in which the price is paid
+	///
+	/// Output:
+	/// - The marketplace fee paid to the `MarketplaceFeeRecipient`
+	/// - The royalty recipient and the amount they were paid, if the NFT has a royalty
+	fn pay_owner(
+		owner: &T::AccountId,
+		payer: &T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		price: BalanceOf<T>,
+		asset: PaymentAssetOf<T>,
+	) -> Result<(BalanceOf<T>, Option<(T::AccountId, BalanceOf<T>)>), DispatchError> {
+		// Transfer the marketplace fee to the fee recipient
+		let fee = T::MarketplaceFee::get() * price;
+		Self::transfer_asset(asset, payer, &T::MarketplaceFeeRecipient::get(), fee)?;
+		let mut owner_amount = price.saturating_sub(fee);
+
+		// Calculate the royalty cut of the amount left after the fee if the NFT has a royalty set
+		let royalty = pallet_rmrk_core::Pallet::<T>::nfts(collection_id, nft_id)
+			.and_then(|nft| nft.royalty)
+			.map(|royalty_info| (royalty_info.recipient, royalty_info.amount * owner_amount));
+
+		// Transfer the royalty to the recipient and the rest to the owner
+		if let Some((recipient, royalty_amount)) = &royalty {
+			Self::transfer_asset(asset, payer, recipient, *royalty_amount)?;
+			owner_amount = owner_amount.saturating_sub(*royalty_amount);
+		}
+		Self::transfer_asset(asset, payer, owner, owner_amount)?;
+
+		Ok((fee, royalty))
+	}
+
+	/// Helper function to add a sale to the history of the RMRK NFT and of its collection, and
+	/// to the statistics of the collection
+	///
+	/// Parameters:
+	/// - `seller`: The account that sold the RMRK NFT
+	/// - `buyer`: The account that bought the RMRK NFT
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The id of the RMRK NFT
+	/// - `price`: The amount paid by the buyer
+	/// - `asset`: The native currency or asset in which the price was paid
+	fn record_sale(
+		seller: T::AccountId,
+		buyer: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		price: BalanceOf<T>,
+		asset: PaymentAssetOf<T>,
+	) {
+		let block = <frame_system::Pallet<T>>::block_number();
+		let record = SaleRecord { seller, buyer, nft_id, price, asset, block };
+
+		NftSales::<T>::mutate(collection_id, nft_id, |sales| {
+			Self::push_sale(sales, record.clone())
+		});
+		CollectionSales::<T>::mutate(collection_id, |sales| Self::push_sale(sales, record));
+		CollectionSaleStats::<T>::mutate(collection_id, |stats| {
+			stats.sales = stats.sales.saturating_add(1);
+			// Prices in different assets cannot be added up, only the native currency is counted
+			if matches!(asset, PaymentAsset::Native) {
+				stats.volume = stats.volume.saturating_add(price);
+				stats.last_sale = Some((price, block));
+			}
+		});
+	}
+
+	/// Helper function to add a sale to a history, dropping the oldest sale once the history is
+	/// full
+	///
+	/// Parameters:
+	/// - `sales`: The history of sales
+	/// - `record`: The sale to add
+	fn push_sale(
+		sales: &mut BoundedVec<SaleRecordOf<T>, T::MaxSaleHistory>,
+		record: SaleRecordOf<T>,
+	) {
+		if !sales.is_empty() && sales.len() >= T::MaxSaleHistory::get() as usize {
+			sales.remove(0);
+		}
+		let _ = sales.try_push(record);
+	}
+
+	/// Helper function to remove a listing and its reservation, and return its deposit
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	///
+	/// Output:
+	/// - The removed listing, if the RMRK NFT was listed
+	fn remove_listing(collection_id: CollectionId, nft_id: NftId) -> Option<ListInfoOf<T>> {
+		let list_info = ListedNfts::<T>::take(collection_id, nft_id)?;
+		if let Some(reserved_for) = &list_info.reserved_for {
+			ReservedListings::<T>::remove(reserved_for, (collection_id, nft_id));
+		}
+		<T as pallet::Config>::Currency::unreserve(&list_info.listed_by, list_info.deposit);
+		Some(list_info)
+	}
+
+	/// Helper function to remove a listing without checking who listed it, unlocking the RMRK
+	/// NFT if it is still owned by the account that listed it
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	///
+	/// Output:
+	/// - Whether the RMRK NFT was listed
+	fn cancel_listing(collection_id: CollectionId, nft_id: NftId) -> bool {
+		let list_info = match Self::remove_listing(collection_id, nft_id) {
+			Some(list_info) => list_info,
+			None => return false,
+		};
+		// The NFT is only still locked by the listing if the owner has not changed
+		if Self::root_owner(collection_id, nft_id)
+			.map_or(false, |owner| owner == list_info.listed_by)
+		{
+			pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+		}
+		Self::deposit_event(Event::TokenUnlisted {
+			owner: list_info.listed_by,
+			collection_id,
+			nft_id,
+		});
+		true
+	}
+
+	/// Helper function to remove an offer without checking who withdraws it, unreserving the
+	/// offered amount
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	/// - `offerer`: The account that made the offer
+	///
+	/// Output:
+	/// - Whether the offer existed
+	fn cancel_offer(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		offerer: T::AccountId,
+	) -> Result<bool, DispatchError> {
+		let offer = match Offers::<T>::take((collection_id, nft_id), &offerer) {
+			Some(offer) => offer,
+			None => return Ok(false),
+		};
+		OffersInCollection::<T>::remove(collection_id, (nft_id, offerer.clone()));
+		Self::unreserve_asset(offer.asset, &offer.maker, offer.amount)?;
+		Self::deposit_event(Event::OfferWithdrawn { sender: offerer, collection_id, nft_id });
+		Ok(true)
+	}
+
+	/// Helper function to get the weight of cancelling one listing, offer, bundle, swap or auction
+	/// in `force_cancel_all_for_collection`, counting the largest bundle or swap
+	///
+	/// Output:
+	/// - The weight of the most expensive item
+	fn force_cancel_weight_per_item() -> Weight {
+		let max_nfts = T::MaxNftsPerBundle::get().max(T::MaxNftsPerSwap::get()) as Weight;
+		T::DbWeight::get()
+			.reads_writes(4, 4)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 3).saturating_mul(max_nfts))
+	}
+
+	/// Helper function to ensure neither the Marketplace nor a collection is paused
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFT
+	fn ensure_not_paused(collection_id: CollectionId) -> DispatchResult {
+		ensure!(!Self::market_paused(), Error::<T>::MarketPaused);
+		ensure!(!PausedCollections::<T>::contains_key(collection_id), Error::<T>::CollectionPaused);
+		Ok(())
+	}
+
+	/// Helper function to get the buyer a listing is reserved for, if the reservation has not
+	/// ended yet
+	///
+	/// Parameters:
+	/// - `list_info`: The listing of the RMRK NFT
+	fn active_reservation(list_info: &ListInfoOf<T>) -> Option<&T::AccountId> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		match list_info.reservation_expires {
+			Some(expires) if expires <= now => None,
+			_ => list_info.reserved_for.as_ref(),
+		}
+	}
+
+	/// Helper function to get the current price of a listing. The price of a Dutch auction is
+	/// interpolated linearly between its start and end price from the current block.
+	///
+	/// Parameters:
+	/// - `list_info`: The listing of the RMRK NFT
+	fn current_price(list_info: &ListInfoOf<T>) -> BalanceOf<T> {
+		match list_info.kind {
+			ListingKind::FixedPrice => list_info.amount,
+			ListingKind::Dutch { end_price, start, duration } => {
+				let elapsed =
+					<frame_system::Pallet<T>>::block_number().saturating_sub(start).min(duration);
+				let decrease = Perbill::from_rational(elapsed, duration) *
+					list_info.amount.saturating_sub(end_price);
+				list_info.amount.saturating_sub(decrease)
+			},
+		}
+	}
+
+	/// Helper function to settle an auction once it has ended. The highest bid is unreserved
+	/// and used to buy the NFT. If the sale fails, the highest bidder keeps their unreserved
+	/// funds and the NFT is unlocked.
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	fn settle_auction(collection_id: CollectionId, nft_id: NftId) {
+		let auction = match Auctions::<T>::take(collection_id, nft_id) {
+			Some(auction) => auction,
+			None => return,
+		};
+		pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+
+		match auction.highest_bid {
+			Some((winner, price)) => {
+				<T as pallet::Config>::Currency::unreserve(&winner, price);
+				match Self::do_auction_sale(
+					auction.seller.clone(),
+					winner.clone(),
+					collection_id,
+					nft_id,
+					price,
+				) {
+					Ok((fee, royalty)) => Self::deposit_event(Event::AuctionSettled {
+						owner: auction.seller,
+						winner,
+						collection_id,
+						nft_id,
+						price,
+						fee,
+						royalty,
+					}),
+					Err(error) => Self::deposit_event(Event::AuctionSettlementFailed {
+						collection_id,
+						nft_id,
+						error,
+					}),
+				}
+			},
+			None => Self::deposit_event(Event::AuctionEndedWithoutBids {
+				owner: auction.seller,
+				collection_id,
+				nft_id,
+			}),
+		}
+	}
+
+	/// Helper function to sell an auctioned NFT to the winner of the auction, reverting all
+	/// changes if the sale fails
+	///
+	/// Parameters:
+	/// - `seller`: The account that created the auction
+	/// - `winner`: The highest bidder of the auction
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	/// - `price`: The highest bid of the auction
+	#[transactional]
+	fn do_auction_sale(
+		seller: T::AccountId,
+		winner: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		price: BalanceOf<T>,
+	) -> Result<(BalanceOf<T>, Option<(T::AccountId, BalanceOf<T>)>), DispatchError> {
+		// Ensure that the seller still owns the NFT
+		let owner = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
+			.ok_or(Error::<T>::TokenDoesNotExist)?;
+		ensure!(owner == seller, Error::<T>::NoPermission);
+
+		Self::do_sale(seller, winner, collection_id, nft_id, price, PaymentAsset::Native)
+	}
+
+	/// Helper function to add an auction to the auctions settled at a block
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	/// - `end`: The block at which the auction is settled
+	fn schedule_auction_end(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		end: T::BlockNumber,
+	) -> DispatchResult {
+		AuctionsEndingAt::<T>::try_mutate(end, |auctions| -> DispatchResult {
+			auctions
+				.try_push((collection_id, nft_id))
+				.map_err(|_| Error::<T>::TooManyAuctionsEndingAtBlock)?;
+			Ok(())
+		})
+	}
+
+	/// Helper function to add an extended auction to the auctions settled at the first block
+	/// from `earliest_end` on that has room for it. Only the next `AuctionExtendPeriod` blocks
+	/// are searched, so that a block filled with auctions does not reject a late bid.
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	/// - `earliest_end`: The earliest block at which the auction can be settled
+	///
+	/// Output:
+	/// - The block at which the auction is settled
+	fn schedule_extended_auction_end(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		earliest_end: T::BlockNumber,
+	) -> Result<T::BlockNumber, DispatchError> {
+		let mut end = earliest_end;
+		let last_end = earliest_end.saturating_add(T::AuctionExtendPeriod::get());
+		while end < last_end {
+			if Self::schedule_auction_end(collection_id, nft_id, end).is_ok() {
+				return Ok(end)
+			}
+			end = end.saturating_add(1u32.into());
+		}
+		Err(Error::<T>::TooManyAuctionsEndingAtBlock.into())
+	}
+
+	/// Helper function to remove an auction from the auctions settled at a block
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	/// - `end`: The block at which the auction was going to be settled
+	fn unschedule_auction_end(collection_id: CollectionId, nft_id: NftId, end: T::BlockNumber) {
+		AuctionsEndingAt::<T>::mutate_exists(end, |maybe_auctions| {
+			if let Some(auctions) = maybe_auctions {
+				auctions.retain(|token_id| *token_id != (collection_id, nft_id));
+				if auctions.is_empty() {
+					*maybe_auctions = None;
+				}
+			}
+		});
+	}
+
+	/// Helper function to index a listing or offer by the block at which it expires, so that it
+	/// is cleaned up in `on_idle`. Listings and offers that are already expired are indexed at
+	/// the current block.
+	///
+	/// Parameters:
+	/// - `expires`: Optional BlockNumber for when the listing or offer expires
+	/// - `expiring`: The listing or offer
+	fn schedule_expiration(expires: Option<T::BlockNumber>, expiring: ExpiringOf<T>) {
+		if let Some(expires) = expires {
+			let block = expires.max(<frame_system::Pallet<T>>::block_number());
+			Expirations::<T>::insert(block, expiring, ());
+		}
+	}
+
+	/// Helper function to remove a listing or offer if it has expired, unlocking the NFT or
+	/// unreserving the offered amount. Listings and offers that were removed or replaced since
+	/// they were indexed are ignored. Nothing is removed if refunding an offer fails, so that the
+	/// offer can still be withdrawn.
+	///
+	/// Parameters:
+	/// - `expiring`: The listing or offer
+	/// - `now`: The current block
+	#[transactional]
+	fn expire(expiring: ExpiringOf<T>, now: T::BlockNumber) -> DispatchResult {
+		let has_expired = |expires: Option<T::BlockNumber>| expires.map_or(false, |e| e <= now);
+
+		match expiring {
+			Expiring::Listing(collection_id, nft_id) => {
+				let list_info = match ListedNfts::<T>::get(collection_id, nft_id) {
+					Some(list_info) if has_expired(list_info.expires) => list_info,
+					_ => return Ok(()),
+				};
+				Self::remove_listing(collection_id, nft_id);
+				// The NFT is only still locked by the listing if the owner has not changed
+				if Self::root_owner(collection_id, nft_id)
+					.map_or(false, |owner| owner == list_info.listed_by)
+				{
+					pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+				}
+				Self::deposit_event(Event::ListingExpired {
+					owner: list_info.listed_by,
+					collection_id,
+					nft_id,
+				});
+			},
+			Expiring::Offer(collection_id, nft_id, offerer) => {
+				let token_id = (collection_id, nft_id);
+				let offer = match Offers::<T>::get(token_id, &offerer) {
+					Some(offer) if has_expired(offer.expires) => offer,
+					_ => return Ok(()),
+				};
+				Offers::<T>::remove(token_id, &offerer);
+				OffersInCollection::<T>::remove(collection_id, (nft_id, offerer.clone()));
+				Self::unreserve_asset(offer.asset, &offer.maker, offer.amount)?;
+				Self::deposit_event(Event::OfferExpired { offerer, collection_id, nft_id });
+			},
+			Expiring::CollectionOffer(collection_id, offerer) => {
+				let offer = match CollectionOffers::<T>::get(collection_id, &offerer) {
+					Some(offer) if has_expired(offer.expires) => offer,
+					_ => return Ok(()),
+				};
+				CollectionOffers::<T>::remove(collection_id, &offerer);
+				<T as pallet::Config>::Currency::unreserve(&offer.maker, offer.reserved);
+				Self::deposit_event(Event::CollectionOfferExpired { offerer, collection_id });
+			},
+			Expiring::Bundle(bundle_id) => {
+				let bundle = match Bundles::<T>::get(bundle_id) {
+					Some(bundle) if has_expired(bundle.expires) => bundle,
+					_ => return Ok(()),
+				};
+				Bundles::<T>::remove(bundle_id);
+				Self::release_bundle(&bundle);
+				Self::deposit_event(Event::BundleExpired { bundle_id, owner: bundle.listed_by });
+			},
+		}
+
+		Ok(())
+	}
+
+	/// Helper function to lock a transferable and unequipped RMRK NFT that is not already locked
+	/// by a listing, an auction, a bundle or a swap
+	///
+	/// Parameters:
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	fn lock_unlocked_nft(collection_id: CollectionId, nft_id: NftId) -> DispatchResult {
+		ensure!(
+			!pallet_rmrk_core::Pallet::<T>::lock((collection_id, nft_id)),
+			Error::<T>::TokenLocked
+		);
+		let nft = pallet_rmrk_core::Pallet::<T>::nfts(collection_id, nft_id)
+			.ok_or(Error::<T>::TokenDoesNotExist)?;
+		// Check NFT is transferable and is not equipped, as it could not be sent
+		pallet_rmrk_core::Pallet::<T>::check_is_transferable(&nft)?;
+		pallet_rmrk_core::Pallet::<T>::check_is_not_equipped(&nft)?;
+
+		pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), true);
+
+		Ok(())
+	}
+
+	/// Helper function to lock a RMRK NFT for a swap
+	///
+	/// Parameters:
+	/// - `swap_id`: The id of the swap
+	/// - `collection_id`: The collection id of the RMRK NFT
+	/// - `nft_id`: The nft id of the RMRK NFT
+	fn lock_for_swap(
+		swap_id: SwapId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> DispatchResult {
+		Self::lock_unlocked_nft(collection_id, nft_id)?;
+		NftsInSwaps::<T>::insert(collection_id, nft_id, swap_id);
+		SwapsInCollection::<T>::insert(collection_id, swap_id, ());
+
+		Ok(())
+	}
+
+	/// Helper function to unlock the RMRK NFTs of a bundle and return its deposit
+	///
+	/// Parameters:
+	/// - `bundle`: The bundle
+	fn release_bundle(bundle: &BundleInfoOf<T>) {
+		for (collection_id, nft_id) in bundle.nfts.iter().copied() {
+			pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+			NftsInBundles::<T>::remove(collection_id, nft_id);
+		}
+		<T as pallet::Config>::Currency::unreserve(&bundle.listed_by, bundle.deposit);
+	}
+
+	/// Helper function to check if a RMRK NFT is in a bundle
+	///
+	/// Parameters:
+	/// - collection_id: The collection id of the RMRK NFT
+	/// - nft_id: The nft id of the RMRK NFT
+	fn is_nft_in_bundle(collection_id: CollectionId, nft_id: NftId) -> bool {
+		NftsInBundles::<T>::contains_key(collection_id, nft_id)
+	}
+
+	/// Helper function to unlock the offered RMRK NFTs of a swap and unreserve its top up and
+	/// deposit
+	///
+	/// Parameters:
+	/// - `swap_id`: The id of the swap
+	/// - `swap`: The swap
+	fn release_swap(swap_id: SwapId, swap: &SwapInfoOf<T>) {
+		for (collection_id, nft_id) in swap.offered.iter().copied() {
+			pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
+			NftsInSwaps::<T>::remove(collection_id, nft_id);
+			SwapsInCollection::<T>::remove(collection_id, swap_id);
+		}
+		SwapsInCollection::<T>::remove(swap.target.collection_id(), swap_id);
+		<T as pallet::Config>::Currency::unreserve(
+			&swap.proposer,
+			swap.top_up.saturating_add(swap.deposit),
+		);
+	}
+
+	/// Helper function to check if a RMRK NFT is in a swap
+	///
+	/// Parameters:
+	/// - collection_id: The collection id of the RMRK NFT
+	/// - nft_id: The nft id of the RMRK NFT
+	fn is_nft_in_swap(collection_id: CollectionId, nft_id: NftId) -> bool {
+		NftsInSwaps::<T>::contains_key(collection_id, nft_id)
+	}
+
+	/// Helper function to check if a RMRK NFT is in an auction
+	///
+	/// Parameters:
+	/// - collection_id: The collection id of the RMRK NFT
+	/// - nft_id: The nft id of the RMRK NFT
+	fn is_nft_in_auction(collection_id: CollectionId, nft_id: NftId) -> bool {
+		Auctions::<T>::contains_key(collection_id, nft_id)
+	}
+
+	/// Helper function to check if a RMRK NFT is listed
+	///
+	/// Parameters:
+	/// - collection_id: The collection id of the RMRK NFT
+	/// - nft_id: The nft id of the RMRK NFT
+	fn is_nft_listed(collection_id: CollectionId, nft_id: NftId) -> bool {
+		ListedNfts::<T>::contains_key(collection_id, nft_id)
+	}
+
+	/// Helper function to check if an account has already submitted an offer on a RMRK NFT
+	///
+	/// Parameters:
+	/// - collection_id: The collection id of the RMRK NFT
+	/// - nft_id: The nft id of the RMRK NFT
+	/// - sender: The account that may or may not have already sent an offer
+	fn has_active_offer(collection_id: CollectionId, nft_id: NftId, sender: T::AccountId) -> bool {
+		Offers::<T>::contains_key((collection_id, nft_id), sender)
+	}
+
+	/// Helper function to get the minimum amount of an offer, the `MinimumOfferAmount` for the
+	/// native currency and the minimum balance of an asset, so that the amounts held in the
+	/// pallet account never fall below it
+	///
+	/// Parameters:
+	/// - `asset`: The native currency or asset of the offer
+	fn minimum_offer_amount(asset: PaymentAssetOf<T>) -> BalanceOf<T> {
+		match asset {
+			PaymentAsset::Native => T::MinimumOfferAmount::get(),
+			PaymentAsset::Asset(asset_id) =>
+				<T::Assets as fungibles::Inspect<T::AccountId>>::minimum_balance(asset_id),
+		}
+	}
+
+	/// The account holding the amounts offered in `Assets`
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Helper function to transfer an amount of the native currency or of an asset, keeping the
+	/// source account alive. As offered assets are moved to the pallet account with
+	/// `reserve_asset`, which keeps the offerer alive too, an offerer refunded with
+	/// `unreserve_asset` can always pay the offered amount.
+	///
+	/// Parameters:
+	/// - `asset`: The native currency or asset to transfer
+	/// - `source`: The account the amount is transferred from
+	/// - `dest`: The account the amount is transferred to
+	/// - `amount`: The amount to transfer
+	fn transfer_asset(
+		asset: PaymentAssetOf<T>,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		match asset {
+			PaymentAsset::Native => <T as pallet::Config>::Currency::transfer(
+				source,
+				dest,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			),
+			PaymentAsset::Asset(asset_id) =>
+				<T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+					asset_id, source, dest, amount, true,
+				)
+				.map(|_| ()),
+		}
+	}
+
+	/// Helper function to reserve an offered amount. The native currency is reserved on the
+	/// account, while assets are held in the pallet account.
+	///
+	/// Parameters:
+	/// - `asset`: The native currency or asset of the amount
+	/// - `who`: The account making the offer
+	/// - `amount`: The offered amount
+	fn reserve_asset(
+		asset: PaymentAssetOf<T>,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		match asset {
+			PaymentAsset::Native => <T as pallet::Config>::Currency::reserve(who, amount),
+			PaymentAsset::Asset(_) => Self::transfer_asset(asset, who, &Self::account_id(), amount),
+		}
+	}
+
+	/// Helper function to unreserve an offered amount reserved with `reserve_asset`. Assets are
+	/// transferred back from the pallet account without keeping it alive, as it only holds
+	/// offered amounts.
+	///
+	/// Parameters:
+	/// - `asset`: The native currency or asset of the amount
+	/// - `who`: The account that made the offer
+	/// - `amount`: The offered amount
+	fn unreserve_asset(
+		asset: PaymentAssetOf<T>,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		match asset {
+			PaymentAsset::Native => {
+				<T as pallet::Config>::Currency::unreserve(who, amount);
+			},
+			PaymentAsset::Asset(asset_id) => {
+				<T::Assets as fungibles::Transfer<T::AccountId>>::transfer(
+					asset_id,
+					&Self::account_id(),
+					who,
+					amount,
+					false,
+				)?;
+			},
+		}
+		Ok(())
+	}
+
+	/// Helper function to get the root owner of a NFT, that is the account owning the NFT
+	/// or the top-most NFT it is nested in
+	///
+	/// Parameters:
+	/// - collection_id: The collection id of the RMRK NFT
+	/// - nft_id: The nft id of the RMRK NFT
+	fn root_owner(collection_id: CollectionId, nft_id: NftId) -> Result<T::AccountId, Error<T>> {
+		pallet_rmrk_core::Pallet::<T>::lookup_root_owner(collection_id, nft_id)
+			.map(|(root_owner, _)| root_owner)
+			.map_err(|_| Error::<T>::TokenDoesNotExist)
 	}
 
 	/// Helper function to check if the NFT's parent is a User Account
@@ -0,0 +1,99 @@
+// Copyright (C) 2021-2022 RMRK
+// This file is part of rmrk-market.
+// License: Apache 2.0 modified by RMRK, see LICENSE.md
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, StorageVersion},
+};
+
+/// Layouts of the listings and offers before storage version 1
+pub mod v0 {
+	use super::*;
+
+	#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug)]
+	pub struct ListInfo<AccountId, Balance, BlockNumber> {
+		/// Owner who listed the NFT at the time
+		pub listed_by: AccountId,
+		/// Listed amount
+		pub amount: Balance,
+		/// After this block the listing can't be bought
+		pub expires: Option<BlockNumber>,
+	}
+
+	#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug)]
+	pub struct Offer<AccountId, Balance, BlockNumber> {
+		/// User who made the offer
+		pub maker: AccountId,
+		/// Offered amount
+		pub amount: Balance,
+		/// After this block the offer can't be accepted
+		pub expires: Option<BlockNumber>,
+	}
+}
+
+/// Migrate the listings and offers to storage version 1. Existing listings are sold at a fixed
+/// price in the native currency, are not reserved for a buyer and hold no deposit. Existing
+/// offers are paid in the native currency and are indexed in `OffersInCollection`. Listings and
+/// offers with an expiration are indexed in `Expirations` to be cleaned up in `on_idle`, from the
+/// current block on.
+///
+/// Output:
+/// - The weight consumed by the migration
+pub fn migrate_to_v1<T: Config>() -> Weight
+where
+	T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+{
+	if Pallet::<T>::on_chain_storage_version() >= 1 {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut listings: Weight = 0;
+	ListedNfts::<T>::translate::<v0::ListInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>, _>(
+		|collection_id, nft_id, old| {
+			listings += 1;
+			Pallet::<T>::schedule_expiration(old.expires, Expiring::Listing(collection_id, nft_id));
+			Some(ListInfo {
+				listed_by: old.listed_by,
+				amount: old.amount,
+				asset: PaymentAsset::Native,
+				expires: old.expires,
+				kind: ListingKind::FixedPrice,
+				reserved_for: None,
+				reservation_expires: None,
+				deposit: Zero::zero(),
+			})
+		},
+	);
+
+	let mut offers: Weight = 0;
+	Offers::<T>::translate::<v0::Offer<T::AccountId, BalanceOf<T>, T::BlockNumber>, _>(
+		|(collection_id, nft_id), offerer, old| {
+			offers += 1;
+			OffersInCollection::<T>::insert(collection_id, (nft_id, offerer.clone()), ());
+			Pallet::<T>::schedule_expiration(
+				old.expires,
+				Expiring::Offer(collection_id, nft_id, offerer),
+			);
+			Some(Offer {
+				maker: old.maker,
+				amount: old.amount,
+				asset: PaymentAsset::Native,
+				expires: old.expires,
+			})
+		},
+	);
+
+	// Expirations are indexed from the current block on, so there is nothing to clean before it
+	NextExpirationToClean::<T>::put(<frame_system::Pallet<T>>::block_number());
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(
+		listings.saturating_add(offers).saturating_add(2),
+		listings
+			.saturating_mul(2)
+			.saturating_add(offers.saturating_mul(3))
+			.saturating_add(2),
+	)
+}
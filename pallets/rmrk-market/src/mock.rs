@@ -9,6 +9,7 @@ use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32, Everything},
 	weights::Weight,
+	PalletId,
 };
 use frame_system as system;
 use frame_system::EnsureRoot;
@@ -17,6 +18,7 @@ use sp_core::{crypto::AccountId32, H256};
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	MultiSignature, MultiSigner, Permill,
 };
 
 mod rmrk_market {
@@ -38,6 +40,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
 		RmrkCore: pallet_rmrk_core::{Pallet, Call, Event<T>, Storage},
 		RmrkMarket: pallet_rmrk_market::{Pallet, Call, Storage, Event<T>},
@@ -94,6 +97,32 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * UNITS;
+	pub const AssetAccountDeposit: Balance = UNITS;
+	pub const AssetsStringLimit: u32 = 50;
+	pub const MetadataDepositBase: Balance = 10 * UNITS;
+	pub const MetadataDepositPerByte: Balance = UNITS;
+	pub const ApprovalDeposit: Balance = UNITS;
+}
+
+impl pallet_assets::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = AssetsStringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub MaxMetadataLength: u32 = 256;
 	pub const MaxRecursions: u32 = 10;
@@ -102,6 +131,9 @@ parameter_types! {
 	pub const MaxPriorities: u32 = 3;
 	pub const CollectionSymbolLimit: u32 = 100;
 	pub const MaxResourcesOnMint: u32 = 3;
+	pub const MaxBatchMint: u32 = 3;
+	pub const MaxCollectionRoles: u32 = 4;
+	pub const MaxPropertyPermissions: u32 = 4;
 }
 
 impl pallet_rmrk_core::Config for Test {
@@ -114,6 +146,11 @@ impl pallet_rmrk_core::Config for Test {
 	type MaxPriorities = MaxPriorities;
 	type CollectionSymbolLimit = CollectionSymbolLimit;
 	type MaxResourcesOnMint = MaxResourcesOnMint;
+	type MaxBatchMint = MaxBatchMint;
+	type MaxCollectionRoles = MaxCollectionRoles;
+	type MaxPropertyPermissions = MaxPropertyPermissions;
+	type OffchainSignature = MultiSignature;
+	type OffchainPublic = MultiSigner;
 }
 
 parameter_types! {
@@ -149,18 +186,42 @@ impl pallet_uniques::Config for Test {
 
 parameter_types! {
 	pub const MinimumOfferAmount: Balance = 50 * UNITS;
+	pub const AuctionExtendPeriod: u64 = 5;
+	pub const MaxAuctionsEndingPerBlock: u32 = 2;
+	pub const MarketplaceFee: Permill = Permill::from_percent(2);
+	pub const MarketplaceFeeRecipient: AccountId = FEE_RECIPIENT;
+	pub const ListingDeposit: Balance = LISTING_DEPOSIT;
+	pub const ListingDepositSlash: Permill = Permill::from_percent(50);
+	pub const MarketPalletId: PalletId = PalletId(*b"rmrk/esc");
+	pub const MaxNftsPerSwap: u32 = 2;
+	pub const MaxNftsPerBundle: u32 = 3;
+	pub const MaxSaleHistory: u32 = 3;
 }
 
 impl Config for Test {
 	type Event = Event;
 	type ProtocolOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PalletId = MarketPalletId;
 	type MinimumOfferAmount = MinimumOfferAmount;
+	type AuctionExtendPeriod = AuctionExtendPeriod;
+	type MaxAuctionsEndingPerBlock = MaxAuctionsEndingPerBlock;
+	type MarketplaceFee = MarketplaceFee;
+	type MarketplaceFeeRecipient = MarketplaceFeeRecipient;
+	type ListingDeposit = ListingDeposit;
+	type ListingDepositSlash = ListingDepositSlash;
+	type Slashed = ();
+	type MaxNftsPerSwap = MaxNftsPerSwap;
+	type MaxNftsPerBundle = MaxNftsPerBundle;
+	type MaxSaleHistory = MaxSaleHistory;
 }
 
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
 pub const BOB: AccountId = AccountId::new([2u8; 32]);
 pub const CHARLIE: AccountId = AccountId::new([3u8; 32]);
+pub const FEE_RECIPIENT: AccountId = AccountId::new([9u8; 32]);
 pub const UNITS: Balance = 100_000_000_000;
 pub const RMRK: Balance = 1;
 pub const COLLECTION_ID_0: <Test as pallet_uniques::Config>::CollectionId = 0;
@@ -169,6 +230,9 @@ pub const NFT_ID_0: <Test as pallet_uniques::Config>::ItemId = 0;
 pub const NFT_ID_1: <Test as pallet_uniques::Config>::ItemId = 1;
 pub const NOT_EXISTING_NFT_ID: <Test as pallet_uniques::Config>::ItemId = 999;
 pub const MIN_OFFER_ON_NFT: Balance = 50 * UNITS;
+pub const LISTING_DEPOSIT: Balance = UNITS;
+pub const NATIVE: PaymentAssetOf<Test> = PaymentAsset::Native;
+pub const ASSET_ID_0: u32 = 0;
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -4,11 +4,16 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{OnIdle, OnInitialize},
+	weights::Weight,
+};
 use mock::{Event as MockEvent, *};
 
 use sp_runtime::Permill;
 use sp_std::{convert::TryInto, vec::Vec};
+use types::{CollectionStats, SaleRecord};
 
 /// Turns a string into a BoundedVec
 fn stb(s: &str) -> BoundedVec<u8, ValueLimit> {
@@ -41,7 +46,7 @@ fn basic_mint(id: u32) -> DispatchResult {
 	RmrkCore::mint_nft(
 		Origin::signed(ALICE),
 		Some(ALICE),
-		id,
+		Some(id),
 		COLLECTION_ID_0,
 		Some(ALICE),
 		Some(Permill::from_float(1.525)),
@@ -51,6 +56,37 @@ fn basic_mint(id: u32) -> DispatchResult {
 	)
 }
 
+/// Shortcut for minting a NFT in Collection ID 0 owned by `owner`
+fn mint_to(owner: sp_core::crypto::AccountId32, id: u32) -> DispatchResult {
+	RmrkCore::mint_nft(
+		Origin::signed(ALICE),
+		Some(owner),
+		Some(id),
+		COLLECTION_ID_0,
+		None,
+		None,
+		bvec![0u8; 20],
+		true,
+		None,
+	)
+}
+
+/// Shortcut for creating asset `ASSET_ID_0` and minting 1000 of it to BOB and to CHARLIE
+fn create_asset() {
+	assert_ok!(Assets::force_create(Origin::root(), ASSET_ID_0, ALICE, true, 1));
+	assert_ok!(Assets::mint(Origin::signed(ALICE), ASSET_ID_0, BOB, 1_000));
+	assert_ok!(Assets::mint(Origin::signed(ALICE), ASSET_ID_0, CHARLIE, 1_000));
+}
+
+/// Runs the market hooks until block `n`
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		RmrkMarket::on_idle(System::block_number(), Weight::MAX);
+		System::set_block_number(System::block_number() + 1);
+		RmrkMarket::on_initialize(System::block_number());
+	}
+}
+
 #[test]
 fn list_works() {
 	new_test_ext().execute_with(|| {
@@ -66,7 +102,7 @@ fn list_works() {
 		assert_eq!(RmrkCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 2);
 		// BOB shouldn't be able to list ALICE's NFT
 		assert_noop!(
-			RmrkMarket::list(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 10u128, None,),
+			RmrkMarket::list(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 10u128, NATIVE, None,),
 			Error::<Test>::NoPermission
 		);
 		// ALICE cannot list a non-existing NFT
@@ -76,6 +112,7 @@ fn list_works() {
 				COLLECTION_ID_0,
 				NOT_EXISTING_NFT_ID,
 				10u128,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::TokenDoesNotExist
@@ -98,10 +135,10 @@ fn list_works() {
 			nft_id: NFT_ID_1,
 			approval_required: false,
 		}));
-		// ALICE cannot list NFT [0,1] bc it is owned by NFT[0,0]
+		// BOB cannot list NFT [0,1] bc its root owner is ALICE
 		assert_noop!(
-			RmrkMarket::list(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_1, 10u128, None,),
-			Error::<Test>::CannotListNftOwnedByNft
+			RmrkMarket::list(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_1, 10u128, NATIVE, None,),
+			Error::<Test>::NoPermission
 		);
 		// ALICE lists the NFT successfully
 		assert_ok!(RmrkMarket::list(
@@ -109,6 +146,7 @@ fn list_works() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			10u128,
+			NATIVE,
 			None,
 		));
 		// Listed NFT should trigger TokenListed event
@@ -117,6 +155,7 @@ fn list_works() {
 			collection_id: 0,
 			nft_id: 0,
 			price: 10u128,
+			asset: NATIVE,
 		}));
 	});
 }
@@ -130,7 +169,7 @@ fn list_non_transferable_fail() {
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
-			NFT_ID_0,
+			Some(NFT_ID_0),
 			COLLECTION_ID_0,
 			Some(ALICE),
 			Some(Permill::from_float(1.525)),
@@ -139,7 +178,7 @@ fn list_non_transferable_fail() {
 			None,
 		));
 		assert_noop!(
-			RmrkMarket::list(Origin::signed(ALICE), COLLECTION_ID_0, 0, 10u128, None,),
+			RmrkMarket::list(Origin::signed(ALICE), COLLECTION_ID_0, 0, 10u128, NATIVE, None,),
 			pallet_rmrk_core::Error::<Test>::NonTransferable
 		);
 	});
@@ -162,6 +201,7 @@ fn buy_works() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			10u128,
+			NATIVE,
 			None,
 		));
 		// Listed NFT should trigger TokenListed event
@@ -170,19 +210,20 @@ fn buy_works() {
 			collection_id: COLLECTION_ID_0,
 			nft_id: NFT_ID_0,
 			price: 10u128,
+			asset: NATIVE,
 		}));
 		// Ensure that ALICE cannot buy the listed NFT
 		assert_noop!(
 			RmrkMarket::buy(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, Some(10u128),),
 			Error::<Test>::CannotBuyOwnToken
 		);
-		// Ensure that ALICE cannot buy the listed NFT
+		// Ensure that BOB cannot buy the listed NFT above their maximum price
 		assert_noop!(
 			RmrkMarket::buy(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, Some(9u128),),
-			Error::<Test>::PriceDiffersFromExpected
+			Error::<Test>::PriceAboveMaximum
 		);
-		// BOB buys the NFT and the NFT is transferred from ALICE to BOB
-		assert_ok!(RmrkMarket::buy(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, Some(10u128),));
+		// BOB buys the NFT at the listed price and the NFT is transferred from ALICE to BOB
+		assert_ok!(RmrkMarket::buy(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, Some(15u128),));
 		// Bought NFT should trigger TokenSold event
 		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenSold {
 			owner: ALICE,
@@ -190,12 +231,230 @@ fn buy_works() {
 			collection_id: 0,
 			nft_id: 0,
 			price: 10u128,
+			asset: NATIVE,
+			fee: 0u128,
+			royalty: Some((ALICE, 10u128)),
 		}));
 		// Ensure BOB is the new owner of NFT (0,0)
 		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
 	});
 }
 
+#[test]
+fn buy_nested_nft_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint two NFTs
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		// ALICE sends NFT [0,1] to NFT [0,0]
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_0),
+		));
+		assert!(pallet_rmrk_core::Children::<Test>::contains_key(
+			(COLLECTION_ID_0, NFT_ID_0),
+			(COLLECTION_ID_0, NFT_ID_1)
+		));
+		// ALICE lists NFT [0,1] as the root owner
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			10u128,
+			NATIVE,
+			None,
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenListed {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_1,
+			price: 10u128,
+			asset: NATIVE,
+		}));
+		// ALICE cannot buy the NFT nested in their own NFT
+		assert_noop!(
+			RmrkMarket::buy(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_1, Some(10u128)),
+			Error::<Test>::CannotBuyOwnToken
+		);
+		// BOB buys NFT [0,1]
+		assert_ok!(RmrkMarket::buy(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_1, Some(10u128)));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenSold {
+			owner: ALICE,
+			buyer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_1,
+			price: 10u128,
+			asset: NATIVE,
+			fee: 0u128,
+			royalty: Some((ALICE, 10u128)),
+		}));
+		// NFT [0,1] is detached from NFT [0,0] and owned by BOB
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_1), Some(BOB));
+		assert!(!pallet_rmrk_core::Children::<Test>::contains_key(
+			(COLLECTION_ID_0, NFT_ID_0),
+			(COLLECTION_ID_0, NFT_ID_1)
+		));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_1)));
+	});
+}
+
+#[test]
+fn buy_pays_royalty_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint an NFT with a 10% royalty paid to CHARLIE
+		assert_ok!(RmrkCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(ALICE),
+			Some(NFT_ID_0),
+			COLLECTION_ID_0,
+			Some(CHARLIE),
+			Some(Permill::from_percent(10)),
+			bvec![0u8; 20],
+			true,
+			None,
+		));
+		// ALICE lists the NFT successfully
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			1_000 * UNITS,
+			NATIVE,
+			None,
+		));
+		let alice_balance = Balances::free_balance(ALICE);
+		let bob_balance = Balances::free_balance(BOB);
+		let charlie_balance = Balances::free_balance(CHARLIE);
+		// BOB buys the NFT
+		assert_ok!(RmrkMarket::buy(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			Some(1_000 * UNITS),
+		));
+		// Bought NFT should trigger TokenSold event with the fee and royalty payouts
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenSold {
+			owner: ALICE,
+			buyer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			price: 1_000 * UNITS,
+			asset: NATIVE,
+			fee: 20 * UNITS,
+			royalty: Some((CHARLIE, 98 * UNITS)),
+		}));
+		// The 2% fee is deducted first, CHARLIE receives the royalty of the remaining amount and
+		// ALICE receives the rest along with the listing deposit
+		assert_eq!(Balances::free_balance(BOB), bob_balance - 1_000 * UNITS);
+		assert_eq!(Balances::free_balance(FEE_RECIPIENT), 20 * UNITS);
+		assert_eq!(Balances::free_balance(CHARLIE), charlie_balance + 98 * UNITS);
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 882 * UNITS + LISTING_DEPOSIT);
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+	});
+}
+
+#[test]
+fn list_dutch_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint two NFTs
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		// End price cannot exceed start price and duration cannot be zero
+		assert_noop!(
+			RmrkMarket::list_dutch(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				50 * UNITS,
+				100 * UNITS,
+				NATIVE,
+				1,
+				10,
+				None,
+			),
+			Error::<Test>::InvalidDutchListing
+		);
+		assert_noop!(
+			RmrkMarket::list_dutch(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				100 * UNITS,
+				50 * UNITS,
+				NATIVE,
+				1,
+				0,
+				None,
+			),
+			Error::<Test>::InvalidDutchListing
+		);
+		// ALICE lists both NFTs in a Dutch auction from 100 to 50 UNITS over 10 blocks
+		for nft_id in [NFT_ID_0, NFT_ID_1] {
+			assert_ok!(RmrkMarket::list_dutch(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				nft_id,
+				100 * UNITS,
+				50 * UNITS,
+				NATIVE,
+				1,
+				10,
+				None,
+			));
+		}
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenListedDutch {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_1,
+			start_price: 100 * UNITS,
+			end_price: 50 * UNITS,
+			asset: NATIVE,
+			start: 1,
+			duration: 10,
+		}));
+		assert_eq!(RmrkMarket::listing_price(COLLECTION_ID_0, NFT_ID_0), Some(100 * UNITS));
+		// Price decreases linearly over the duration
+		run_to_block(6);
+		assert_eq!(RmrkMarket::listing_price(COLLECTION_ID_0, NFT_ID_0), Some(75 * UNITS));
+		// BOB cannot buy the NFT above their maximum price
+		assert_noop!(
+			RmrkMarket::buy(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, Some(70 * UNITS)),
+			Error::<Test>::PriceAboveMaximum
+		);
+		// BOB buys the NFT at the current price
+		assert_ok!(RmrkMarket::buy(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			Some(80 * UNITS),
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenSold {
+			owner: ALICE,
+			buyer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			price: 75 * UNITS,
+			asset: NATIVE,
+			fee: 15 * UNITS / 10,
+			royalty: Some((ALICE, 735 * UNITS / 10)),
+		}));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		assert_eq!(Balances::free_balance(BOB), 200_000 * UNITS - 75 * UNITS);
+		// Price stays at the end price once the duration has passed
+		run_to_block(20);
+		assert_eq!(RmrkMarket::listing_price(COLLECTION_ID_0, NFT_ID_1), Some(50 * UNITS));
+		assert_eq!(RmrkMarket::listing_price(COLLECTION_ID_0, NFT_ID_0), None);
+	});
+}
+
 #[test]
 fn buy_wont_work_after_list_expires() {
 	new_test_ext().execute_with(|| {
@@ -213,6 +472,7 @@ fn buy_wont_work_after_list_expires() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			10u128,
+			NATIVE,
 			Some(1),
 		));
 		// Listed NFT should trigger TokenListed event
@@ -221,6 +481,7 @@ fn buy_wont_work_after_list_expires() {
 			collection_id: COLLECTION_ID_0,
 			nft_id: NFT_ID_0,
 			price: 10u128,
+			asset: NATIVE,
 		}));
 		// Set block number to expired block
 		System::set_block_number(2);
@@ -249,6 +510,7 @@ fn send_wont_work_if_sent_after_list() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			10u128,
+			NATIVE,
 			None,
 		));
 		// Listed NFT should trigger TokenListed event
@@ -257,6 +519,7 @@ fn send_wont_work_if_sent_after_list() {
 			collection_id: COLLECTION_ID_0,
 			nft_id: NFT_ID_0,
 			price: 10u128,
+			asset: NATIVE,
 		}));
 		// Ensure that ALICE cannot buy the listed NFT
 		assert_noop!(
@@ -284,6 +547,9 @@ fn send_wont_work_if_sent_after_list() {
 			collection_id: 0,
 			nft_id: 0,
 			price: 10u128,
+			asset: NATIVE,
+			fee: 0u128,
+			royalty: Some((ALICE, 10u128)),
 		}));
 		// Ensure BOB is the still new owner of NFT (0,0)
 		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
@@ -308,6 +574,7 @@ fn send_to_nft_wont_work_after_list() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			10u128,
+			NATIVE,
 			None,
 		));
 		// Listed NFT should trigger TokenListed event
@@ -316,6 +583,7 @@ fn send_to_nft_wont_work_after_list() {
 			collection_id: COLLECTION_ID_0,
 			nft_id: NFT_ID_0,
 			price: 10u128,
+			asset: NATIVE,
 		}));
 		// Ensure that ALICE cannot buy the listed NFT
 		assert_noop!(
@@ -351,6 +619,9 @@ fn send_to_nft_wont_work_after_list() {
 			collection_id: 0,
 			nft_id: 0,
 			price: 10u128,
+			asset: NATIVE,
+			fee: 0u128,
+			royalty: Some((ALICE, 10u128)),
 		}));
 		// Ensure BOB is the still new owner of NFT [0,0]
 		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
@@ -393,6 +664,7 @@ fn accept_offer_wont_work_if_traded_to_nft_after_list() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			MIN_OFFER_ON_NFT,
+			NATIVE,
 			None,
 		));
 		// Offer from BOB on ALICE's NFT should trigger OfferPlaced event
@@ -401,6 +673,7 @@ fn accept_offer_wont_work_if_traded_to_nft_after_list() {
 			collection_id: COLLECTION_ID_0,
 			nft_id: NFT_ID_0,
 			price: MIN_OFFER_ON_NFT,
+			asset: NATIVE,
 		}));
 		// ALICE sends NFT [0,0] to NFT [0.1]
 		assert_ok!(RmrkCore::send(
@@ -443,7 +716,7 @@ fn unlist_works() {
 		assert_eq!(RmrkCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 2);
 		// BOB shouldn't be able to list ALICE's NFT
 		assert_noop!(
-			RmrkMarket::list(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 10u128, None,),
+			RmrkMarket::list(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 10u128, NATIVE, None,),
 			Error::<Test>::NoPermission
 		);
 		// ALICE cannot list a non-existing NFT
@@ -453,6 +726,7 @@ fn unlist_works() {
 				COLLECTION_ID_0,
 				NOT_EXISTING_NFT_ID,
 				10u128,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::TokenDoesNotExist
@@ -468,6 +742,7 @@ fn unlist_works() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			10u128,
+			NATIVE,
 			None,
 		));
 		// Listed NFT should trigger TokenListed event
@@ -476,6 +751,7 @@ fn unlist_works() {
 			collection_id: COLLECTION_ID_0,
 			nft_id: NFT_ID_0,
 			price: 10u128,
+			asset: NATIVE,
 		}));
 		// BOB cannot unlist a NFT if not owned by BOB
 		assert_noop!(
@@ -507,6 +783,7 @@ fn offer_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::TokenDoesNotExist
@@ -522,6 +799,7 @@ fn offer_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::CannotOfferOnOwnToken
@@ -533,6 +811,7 @@ fn offer_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT - 1,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::OfferTooLow
@@ -543,6 +822,7 @@ fn offer_works() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			MIN_OFFER_ON_NFT,
+			NATIVE,
 			None,
 		));
 		// Offer from BOB on ALICE's NFT should trigger OfferPlaced event
@@ -551,6 +831,7 @@ fn offer_works() {
 			collection_id: COLLECTION_ID_0,
 			nft_id: NFT_ID_0,
 			price: MIN_OFFER_ON_NFT,
+			asset: NATIVE,
 		}));
 		// BOB cannot offer again on a NFT with an active offer
 		assert_noop!(
@@ -559,6 +840,7 @@ fn offer_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT + 50,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::AlreadyOffered
@@ -580,6 +862,7 @@ fn offer_withdrawn_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::TokenDoesNotExist
@@ -595,6 +878,7 @@ fn offer_withdrawn_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::CannotOfferOnOwnToken
@@ -606,6 +890,7 @@ fn offer_withdrawn_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT - 1,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::OfferTooLow
@@ -621,6 +906,7 @@ fn offer_withdrawn_works() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			MIN_OFFER_ON_NFT,
+			NATIVE,
 			None,
 		));
 		// Offer from BOB on ALICE's NFT should trigger OfferPlaced event
@@ -629,6 +915,7 @@ fn offer_withdrawn_works() {
 			collection_id: COLLECTION_ID_0,
 			nft_id: NFT_ID_0,
 			price: MIN_OFFER_ON_NFT,
+			asset: NATIVE,
 		}));
 		// ALICE cannot withdraw offer on own NFT
 		assert_noop!(
@@ -665,6 +952,7 @@ fn accept_offer_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::TokenDoesNotExist
@@ -680,6 +968,7 @@ fn accept_offer_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::CannotOfferOnOwnToken
@@ -691,6 +980,7 @@ fn accept_offer_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT - 1,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::OfferTooLow
@@ -701,6 +991,7 @@ fn accept_offer_works() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			MIN_OFFER_ON_NFT,
+			NATIVE,
 			None,
 		));
 		// Offer from BOB on ALICE's NFT should trigger OfferPlaced event
@@ -709,6 +1000,7 @@ fn accept_offer_works() {
 			collection_id: COLLECTION_ID_0,
 			nft_id: NFT_ID_0,
 			price: MIN_OFFER_ON_NFT,
+			asset: NATIVE,
 		}));
 		// ALICE accepts BOB's offer
 		assert_ok!(
@@ -738,6 +1030,7 @@ fn accept_expired_offer_wont_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::TokenDoesNotExist
@@ -753,6 +1046,7 @@ fn accept_expired_offer_wont_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::CannotOfferOnOwnToken
@@ -764,6 +1058,7 @@ fn accept_expired_offer_wont_works() {
 				COLLECTION_ID_0,
 				NFT_ID_0,
 				MIN_OFFER_ON_NFT - 1,
+				NATIVE,
 				None,
 			),
 			Error::<Test>::OfferTooLow
@@ -774,6 +1069,7 @@ fn accept_expired_offer_wont_works() {
 			COLLECTION_ID_0,
 			NFT_ID_0,
 			MIN_OFFER_ON_NFT,
+			NATIVE,
 			Some(1),
 		));
 		// Offer from BOB on ALICE's NFT should trigger OfferPlaced event
@@ -782,6 +1078,7 @@ fn accept_expired_offer_wont_works() {
 			collection_id: COLLECTION_ID_0,
 			nft_id: NFT_ID_0,
 			price: MIN_OFFER_ON_NFT,
+			asset: NATIVE,
 		}));
 		// CHARLIE cannot accepts BOB's offer
 		assert_noop!(
@@ -797,3 +1094,1774 @@ fn accept_expired_offer_wont_works() {
 		);
 	});
 }
+
+#[test]
+fn collection_offer_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint three NFTs
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		assert_ok!(basic_mint(2));
+		// BOB cannot offer on zero NFTs or on a non-existing collection
+		assert_noop!(
+			RmrkMarket::make_collection_offer(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				MIN_OFFER_ON_NFT,
+				0,
+				None,
+			),
+			Error::<Test>::InvalidQuantity
+		);
+		assert_noop!(
+			RmrkMarket::make_collection_offer(
+				Origin::signed(BOB),
+				COLLECTION_ID_1,
+				MIN_OFFER_ON_NFT,
+				2,
+				None,
+			),
+			Error::<Test>::CollectionDoesNotExist
+		);
+		// The total amount to reserve must not overflow
+		assert_noop!(
+			RmrkMarket::make_collection_offer(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				u128::MAX,
+				2,
+				None,
+			),
+			ArithmeticError::Overflow
+		);
+		// BOB offers to buy two NFTs of the collection
+		assert_ok!(RmrkMarket::make_collection_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			MIN_OFFER_ON_NFT,
+			2,
+			None,
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::CollectionOfferPlaced {
+			offerer: BOB,
+			collection_id: COLLECTION_ID_0,
+			price: MIN_OFFER_ON_NFT,
+			quantity: 2,
+		}));
+		assert_eq!(Balances::reserved_balance(BOB), 2 * MIN_OFFER_ON_NFT);
+		assert_noop!(
+			RmrkMarket::make_collection_offer(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				MIN_OFFER_ON_NFT,
+				1,
+				None,
+			),
+			Error::<Test>::AlreadyOffered
+		);
+		// CHARLIE cannot accept the offer with ALICE's NFT
+		assert_noop!(
+			RmrkMarket::accept_collection_offer(
+				Origin::signed(CHARLIE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BOB
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE fills the offer with two NFTs
+		assert_ok!(RmrkMarket::accept_collection_offer(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BOB
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::CollectionOfferAccepted {
+			owner: ALICE,
+			buyer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		assert_eq!(Balances::reserved_balance(BOB), MIN_OFFER_ON_NFT);
+		let offer = RmrkMarket::collection_offers(COLLECTION_ID_0, BOB).unwrap();
+		assert_eq!((offer.quantity, offer.reserved), (1, MIN_OFFER_ON_NFT));
+		assert_ok!(RmrkMarket::accept_collection_offer(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			BOB
+		));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_1), Some(BOB));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		// The offer is removed once filled
+		assert!(RmrkMarket::collection_offers(COLLECTION_ID_0, BOB).is_none());
+		assert_noop!(
+			RmrkMarket::accept_collection_offer(Origin::signed(ALICE), COLLECTION_ID_0, 2, BOB),
+			Error::<Test>::UnknownOffer
+		);
+	});
+}
+
+#[test]
+fn collection_offer_withdraw_and_expiry_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint an NFT
+		assert_ok!(basic_mint(0));
+		// BOB offers to buy three NFTs of the collection until block 5
+		assert_ok!(RmrkMarket::make_collection_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			MIN_OFFER_ON_NFT,
+			3,
+			Some(5),
+		));
+		// The offer cannot be accepted once expired
+		run_to_block(5);
+		assert_noop!(
+			RmrkMarket::accept_collection_offer(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				BOB
+			),
+			Error::<Test>::OfferHasExpired
+		);
+		// BOB withdraws the offer and the reserved amount is returned
+		assert_ok!(RmrkMarket::withdraw_collection_offer(Origin::signed(BOB), COLLECTION_ID_0));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::CollectionOfferWithdrawn {
+			sender: BOB,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_noop!(
+			RmrkMarket::withdraw_collection_offer(Origin::signed(BOB), COLLECTION_ID_0),
+			Error::<Test>::UnknownOffer
+		);
+	});
+}
+
+#[test]
+fn expired_listings_and_offers_are_cleaned_up() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint two NFTs
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		// ALICE lists the first NFT and BOB makes offers, all expiring at block 5
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			10u128,
+			NATIVE,
+			Some(5),
+		));
+		assert_ok!(RmrkMarket::make_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			MIN_OFFER_ON_NFT,
+			NATIVE,
+			Some(5),
+		));
+		assert_ok!(RmrkMarket::make_collection_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			MIN_OFFER_ON_NFT,
+			2,
+			Some(5),
+		));
+		// CHARLIE makes an offer that is withdrawn before it expires
+		assert_ok!(RmrkMarket::make_offer(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			MIN_OFFER_ON_NFT,
+			NATIVE,
+			Some(5),
+		));
+		assert_ok!(RmrkMarket::withdraw_offer(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_1));
+		assert_eq!(Balances::reserved_balance(BOB), 3 * MIN_OFFER_ON_NFT);
+		// Nothing is cleaned up before the listing and offers expire
+		run_to_block(5);
+		assert!(RmrkMarket::listed_nfts(COLLECTION_ID_0, NFT_ID_0).is_some());
+		assert!(RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		// Only part of the expired listing and offers are cleaned up with limited weight
+		assert_eq!(RmrkMarket::on_idle(5, 25_000), 20_000);
+		assert_eq!(RmrkMarket::next_expiration_to_clean(), 5);
+		// The rest is cleaned up in the next block
+		run_to_block(6);
+		assert_eq!(RmrkMarket::next_expiration_to_clean(), 6);
+		assert!(RmrkMarket::expirations(5, Expiring::Listing(COLLECTION_ID_0, NFT_ID_0)).is_none());
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::ListingExpired {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::OfferExpired {
+			offerer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_1,
+		}));
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::CollectionOfferExpired {
+			offerer: BOB,
+			collection_id: COLLECTION_ID_0,
+		}));
+		// The NFT is unlocked and the offered amounts are unreserved
+		assert!(RmrkMarket::listed_nfts(COLLECTION_ID_0, NFT_ID_0).is_none());
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert!(RmrkMarket::offers((COLLECTION_ID_0, NFT_ID_1), BOB).is_none());
+		assert!(RmrkMarket::collection_offers(COLLECTION_ID_0, BOB).is_none());
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+	});
+}
+
+#[test]
+fn failed_expiration_is_retried() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(mint_to(ALICE, NFT_ID_0));
+		create_asset();
+		// BOB makes an offer in an asset, expiring at block 5
+		assert_ok!(RmrkMarket::make_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			100,
+			PaymentAsset::Asset(ASSET_ID_0),
+			Some(5),
+		));
+		// The offered amount cannot be refunded while the pallet account is frozen
+		assert_ok!(Assets::freeze(Origin::signed(ALICE), ASSET_ID_0, RmrkMarket::account_id()));
+		run_to_block(6);
+		assert!(RmrkMarket::offers((COLLECTION_ID_0, NFT_ID_0), BOB).is_some());
+		assert!(
+			RmrkMarket::expirations(5, Expiring::Offer(COLLECTION_ID_0, NFT_ID_0, BOB)).is_none()
+		);
+		// The expiration is retried at the next block
+		assert!(
+			RmrkMarket::expirations(6, Expiring::Offer(COLLECTION_ID_0, NFT_ID_0, BOB)).is_some()
+		);
+		assert_ok!(Assets::thaw(Origin::signed(ALICE), ASSET_ID_0, RmrkMarket::account_id()));
+		run_to_block(7);
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::OfferExpired {
+			offerer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert!(RmrkMarket::offers((COLLECTION_ID_0, NFT_ID_0), BOB).is_none());
+		assert!(
+			RmrkMarket::expirations(6, Expiring::Offer(COLLECTION_ID_0, NFT_ID_0, BOB)).is_none()
+		);
+		assert_eq!(Assets::balance(ASSET_ID_0, BOB), 1_000);
+	});
+}
+
+#[test]
+fn bundle_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint three NFTs
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		assert_ok!(basic_mint(2));
+		// A bundle must contain at least one NFT and cannot contain a listed NFT
+		assert_noop!(
+			RmrkMarket::list_bundle(Origin::signed(ALICE), bvec![], 101u128, None),
+			Error::<Test>::EmptyBundle
+		);
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			2,
+			10u128,
+			NATIVE,
+			None,
+		));
+		assert_noop!(
+			RmrkMarket::list_bundle(
+				Origin::signed(ALICE),
+				bvec![(COLLECTION_ID_0, NFT_ID_0), (COLLECTION_ID_0, 2)],
+				101u128,
+				None,
+			),
+			Error::<Test>::TokenLocked
+		);
+		// ALICE lists two NFTs in a bundle
+		assert_ok!(RmrkMarket::list_bundle(
+			Origin::signed(ALICE),
+			bvec![(COLLECTION_ID_0, NFT_ID_0), (COLLECTION_ID_0, NFT_ID_1)],
+			101u128,
+			None,
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::BundleListed {
+			bundle_id: 0,
+			owner: ALICE,
+			nfts: bvec![(COLLECTION_ID_0, NFT_ID_0), (COLLECTION_ID_0, NFT_ID_1)],
+			price: 101u128,
+		}));
+		assert!(RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert!(RmrkCore::lock((COLLECTION_ID_0, NFT_ID_1)));
+		// A deposit is reserved for the bundle as for the listing of NFT [0,2]
+		assert_eq!(Balances::reserved_balance(ALICE), 2 * LISTING_DEPOSIT);
+		// NFTs in a bundle cannot be listed on their own
+		assert_noop!(
+			RmrkMarket::list(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				10u128,
+				NATIVE,
+				None,
+			),
+			Error::<Test>::TokenInBundle
+		);
+		// The bundle cannot be bought by ALICE or at another price
+		assert_noop!(
+			RmrkMarket::buy_bundle(Origin::signed(ALICE), 0, Some(101u128)),
+			Error::<Test>::CannotBuyOwnToken
+		);
+		assert_noop!(
+			RmrkMarket::buy_bundle(Origin::signed(BOB), 0, Some(100u128)),
+			Error::<Test>::PriceDiffersFromExpected
+		);
+		// BOB buys the bundle, the price is split between the NFTs
+		assert_ok!(RmrkMarket::buy_bundle(Origin::signed(BOB), 0, Some(101u128)));
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::TokenSold {
+			owner: ALICE,
+			buyer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			price: 51u128,
+			asset: NATIVE,
+			fee: 1u128,
+			royalty: Some((ALICE, 50u128)),
+		}));
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::TokenSold {
+			owner: ALICE,
+			buyer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_1,
+			price: 50u128,
+			asset: NATIVE,
+			fee: 1u128,
+			royalty: Some((ALICE, 49u128)),
+		}));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::BundleSold {
+			bundle_id: 0,
+			owner: ALICE,
+			buyer: BOB,
+			price: 101u128,
+			fee: 2u128,
+		}));
+		// Every NFT of the bundle is transferred to BOB and unlocked, and the deposit returned
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_1), Some(BOB));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_1)));
+		assert_eq!(Balances::reserved_balance(ALICE), LISTING_DEPOSIT);
+		assert!(RmrkMarket::bundles(0).is_none());
+		assert!(RmrkMarket::nfts_in_bundles(COLLECTION_ID_0, NFT_ID_0).is_none());
+	});
+}
+
+#[test]
+fn bundle_unlist_and_expiry_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint an NFT
+		assert_ok!(basic_mint(0));
+		// ALICE lists the NFT in a bundle expiring at block 5
+		assert_ok!(RmrkMarket::list_bundle(
+			Origin::signed(ALICE),
+			bvec![(COLLECTION_ID_0, NFT_ID_0)],
+			10u128,
+			Some(5),
+		));
+		// BOB cannot unlist ALICE's bundle
+		assert_noop!(
+			RmrkMarket::unlist_bundle(Origin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+		// The bundle is unlisted once expired
+		run_to_block(6);
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::BundleExpired {
+			bundle_id: 0,
+			owner: ALICE,
+		}));
+		assert!(RmrkMarket::bundles(0).is_none());
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		// ALICE lists the NFT in a bundle again and unlists it, getting the deposit back
+		assert_ok!(RmrkMarket::list_bundle(
+			Origin::signed(ALICE),
+			bvec![(COLLECTION_ID_0, NFT_ID_0)],
+			10u128,
+			None,
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), LISTING_DEPOSIT);
+		assert_ok!(RmrkMarket::unlist_bundle(Origin::signed(ALICE), 1));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::BundleUnlisted {
+			bundle_id: 1,
+			owner: ALICE,
+		}));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert!(RmrkMarket::nfts_in_bundles(COLLECTION_ID_0, NFT_ID_0).is_none());
+	});
+}
+
+#[test]
+fn swap_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint two NFTs for ALICE and two NFTs for BOB
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		assert_ok!(mint_to(BOB, 2));
+		assert_ok!(mint_to(BOB, 3));
+		// ALICE must offer at least one NFT and cannot request an NFT of their own
+		assert_noop!(
+			RmrkMarket::propose_swap(
+				Origin::signed(ALICE),
+				bvec![],
+				0,
+				SwapTarget::Nft(COLLECTION_ID_0, 2)
+			),
+			Error::<Test>::EmptySwap
+		);
+		assert_noop!(
+			RmrkMarket::propose_swap(
+				Origin::signed(ALICE),
+				bvec![(COLLECTION_ID_0, NFT_ID_0)],
+				0,
+				SwapTarget::Nft(COLLECTION_ID_0, NFT_ID_1)
+			),
+			Error::<Test>::CannotSwapWithOwnToken
+		);
+		// ALICE proposes two NFTs and 10 UNITS for BOB's NFT
+		assert_ok!(RmrkMarket::propose_swap(
+			Origin::signed(ALICE),
+			bvec![(COLLECTION_ID_0, NFT_ID_0), (COLLECTION_ID_0, NFT_ID_1)],
+			10 * UNITS,
+			SwapTarget::Nft(COLLECTION_ID_0, 2)
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::SwapProposed {
+			swap_id: 0,
+			proposer: ALICE,
+			offered: bvec![(COLLECTION_ID_0, NFT_ID_0), (COLLECTION_ID_0, NFT_ID_1)],
+			top_up: 10 * UNITS,
+			target: SwapTarget::Nft(COLLECTION_ID_0, 2),
+		}));
+		// The offered NFTs are locked and the top up and deposit are reserved, the requested NFT
+		// stays free
+		assert!(RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert!(RmrkCore::lock((COLLECTION_ID_0, NFT_ID_1)));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, 2)));
+		assert_eq!(Balances::reserved_balance(ALICE), 10 * UNITS + LISTING_DEPOSIT);
+		// NFTs in a swap cannot be listed
+		assert_noop!(
+			RmrkMarket::list(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				10u128,
+				NATIVE,
+				None,
+			),
+			Error::<Test>::TokenInSwap
+		);
+		// Only BOB can accept the swap with the requested NFT
+		assert_noop!(
+			RmrkMarket::accept_swap(Origin::signed(CHARLIE), 0, COLLECTION_ID_0, 2),
+			Error::<Test>::NoPermission
+		);
+		// BOB can still list the requested NFT, but not accept the swap while it is listed
+		assert_ok!(RmrkMarket::list(Origin::signed(BOB), COLLECTION_ID_0, 2, 10u128, NATIVE, None));
+		assert_noop!(
+			RmrkMarket::accept_swap(Origin::signed(BOB), 0, COLLECTION_ID_0, 2),
+			Error::<Test>::TokenLocked
+		);
+		assert_ok!(RmrkMarket::unlist(Origin::signed(BOB), COLLECTION_ID_0, 2));
+		assert_noop!(
+			RmrkMarket::accept_swap(Origin::signed(BOB), 0, COLLECTION_ID_0, 3),
+			Error::<Test>::SwapTargetMismatch
+		);
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(RmrkMarket::accept_swap(Origin::signed(BOB), 0, COLLECTION_ID_0, 2));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::SwapAccepted {
+			swap_id: 0,
+			proposer: ALICE,
+			acceptor: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: 2,
+		}));
+		// The NFTs and top up are exchanged, the NFTs unlocked and the deposit returned
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_1), Some(BOB));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, 2), Some(ALICE));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 10 * UNITS);
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, 2)));
+		assert!(RmrkMarket::swaps(0).is_none());
+		assert!(RmrkMarket::nfts_in_swaps(COLLECTION_ID_0, 2).is_none());
+	});
+}
+
+#[test]
+fn unlist_stale_nested_listing_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		// ALICE nests NFT [0,1] in NFT [0,0] and lists it
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_0),
+		));
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			10 * UNITS,
+			NATIVE,
+			None,
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), LISTING_DEPOSIT);
+		// ALICE sends NFT [0,0] to BOB, the listing of NFT [0,1] is left stale
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+		));
+		// CHARLIE neither owns nor listed the NFT
+		assert_noop!(
+			RmrkMarket::unlist(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_1),
+			Error::<Test>::NoPermission
+		);
+		// ALICE unlists it and gets the deposit back
+		assert_ok!(RmrkMarket::unlist(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_1));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenUnlisted {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_1,
+		}));
+		assert_eq!(RmrkMarket::listed_nfts(COLLECTION_ID_0, NFT_ID_1), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		// BOB, the new root owner, can list the NFT
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_1)));
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			10 * UNITS,
+			NATIVE,
+			None,
+		));
+	});
+}
+
+#[test]
+fn swap_nested_nfts_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// ALICE nests NFT 1 in NFT 0, BOB nests NFT 3 in NFT 2
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		assert_ok!(mint_to(BOB, 2));
+		assert_ok!(mint_to(BOB, 3));
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_0),
+		));
+		assert_ok!(RmrkCore::send(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			3,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, 2),
+		));
+		// BOB cannot offer ALICE's nested NFT
+		assert_noop!(
+			RmrkMarket::propose_swap(
+				Origin::signed(BOB),
+				bvec![(COLLECTION_ID_0, NFT_ID_1)],
+				0,
+				SwapTarget::Collection(COLLECTION_ID_0)
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE cannot request a NFT nested in their own NFT
+		assert_noop!(
+			RmrkMarket::propose_swap(
+				Origin::signed(ALICE),
+				bvec![(COLLECTION_ID_0, NFT_ID_0)],
+				0,
+				SwapTarget::Nft(COLLECTION_ID_0, NFT_ID_1)
+			),
+			Error::<Test>::CannotSwapWithOwnToken
+		);
+		// ALICE, as root owner, proposes their nested NFT for BOB's nested NFT
+		assert_ok!(RmrkMarket::propose_swap(
+			Origin::signed(ALICE),
+			bvec![(COLLECTION_ID_0, NFT_ID_1)],
+			0,
+			SwapTarget::Nft(COLLECTION_ID_0, 3)
+		));
+		// BOB, as root owner, accepts the swap
+		assert_ok!(RmrkMarket::accept_swap(Origin::signed(BOB), 0, COLLECTION_ID_0, 3));
+		// The nested NFTs are detached from their parents and exchanged
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_1), Some(BOB));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, 3), Some(ALICE));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_1)));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, 3)));
+	});
+}
+
+#[test]
+fn swap_for_collection_and_cancel_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint a NFT for ALICE and a NFT for BOB
+		assert_ok!(basic_mint(0));
+		assert_ok!(mint_to(BOB, 2));
+		// ALICE proposes their NFT for any NFT of the collection
+		assert_ok!(RmrkMarket::propose_swap(
+			Origin::signed(ALICE),
+			bvec![(COLLECTION_ID_0, NFT_ID_0)],
+			0,
+			SwapTarget::Collection(COLLECTION_ID_0)
+		));
+		// BOB cannot cancel a swap for any NFT of a collection
+		assert_noop!(RmrkMarket::cancel_swap(Origin::signed(BOB), 0), Error::<Test>::NoPermission);
+		// BOB accepts the swap with their NFT
+		assert_ok!(RmrkMarket::accept_swap(Origin::signed(BOB), 0, COLLECTION_ID_0, 2));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, 2), Some(ALICE));
+		// ALICE proposes to swap back, and BOB as owner of the requested NFT cancels the swap
+		assert_ok!(RmrkMarket::propose_swap(
+			Origin::signed(ALICE),
+			bvec![(COLLECTION_ID_0, 2)],
+			0,
+			SwapTarget::Nft(COLLECTION_ID_0, NFT_ID_0)
+		));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert!(RmrkCore::lock((COLLECTION_ID_0, 2)));
+		assert_eq!(Balances::reserved_balance(ALICE), LISTING_DEPOSIT);
+		assert_ok!(RmrkMarket::cancel_swap(Origin::signed(BOB), 1));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::SwapCancelled {
+			swap_id: 1,
+			sender: BOB,
+		}));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, 2)));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_noop!(
+			RmrkMarket::accept_swap(Origin::signed(BOB), 1, COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::UnknownSwap
+		);
+	});
+}
+
+#[test]
+fn auction_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint an NFT
+		assert_ok!(basic_mint(0));
+		// BOB cannot auction ALICE's NFT
+		assert_noop!(
+			RmrkMarket::create_auction(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				100 * UNITS,
+				10 * UNITS,
+				20,
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE cannot create an auction that ends in the past
+		assert_noop!(
+			RmrkMarket::create_auction(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				100 * UNITS,
+				10 * UNITS,
+				1,
+			),
+			Error::<Test>::InvalidAuctionEnd
+		);
+		// ALICE creates an auction ending at block 20
+		assert_ok!(RmrkMarket::create_auction(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			100 * UNITS,
+			10 * UNITS,
+			20,
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::AuctionCreated {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			reserve_price: 100 * UNITS,
+			min_bid_increment: 10 * UNITS,
+			end: 20,
+		}));
+		// Auctioned NFT is locked and cannot be listed
+		assert_noop!(
+			RmrkCore::send(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+			),
+			pallet_uniques::Error::<Test>::Locked
+		);
+		assert_noop!(
+			RmrkMarket::list(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				10u128,
+				NATIVE,
+				None,
+			),
+			Error::<Test>::TokenInAuction
+		);
+		// ALICE cannot bid in their own auction
+		assert_noop!(
+			RmrkMarket::bid(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 100 * UNITS),
+			Error::<Test>::CannotBidOnOwnAuction
+		);
+		// BOB cannot bid below the reserve price
+		assert_noop!(
+			RmrkMarket::bid(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 99 * UNITS),
+			Error::<Test>::BidTooLow
+		);
+		// BOB bids the reserve price
+		assert_ok!(RmrkMarket::bid(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 100 * UNITS));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::BidPlaced {
+			bidder: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			amount: 100 * UNITS,
+		}));
+		assert_eq!(Balances::reserved_balance(BOB), 100 * UNITS);
+		// ALICE cannot cancel an auction with bids
+		assert_noop!(
+			RmrkMarket::cancel_auction(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::CannotCancelAuctionWithBids
+		);
+		// CHARLIE must bid at least the minimum increment over BOB's bid
+		assert_noop!(
+			RmrkMarket::bid(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0, 109 * UNITS),
+			Error::<Test>::BidTooLow
+		);
+		// CHARLIE outbids BOB and BOB is refunded
+		assert_ok!(RmrkMarket::bid(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			110 * UNITS
+		));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 110 * UNITS);
+		let alice_balance = Balances::free_balance(ALICE);
+		let charlie_balance = Balances::free_balance(CHARLIE);
+		// Auction is settled at block 20
+		run_to_block(20);
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::AuctionSettled {
+			owner: ALICE,
+			winner: CHARLIE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			price: 110 * UNITS,
+			fee: 22 * UNITS / 10,
+			royalty: Some((ALICE, 1_078 * UNITS / 10)),
+		}));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(CHARLIE));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(Balances::free_balance(CHARLIE), charlie_balance);
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 1_078 * UNITS / 10);
+		assert!(RmrkMarket::auctions(COLLECTION_ID_0, NFT_ID_0).is_none());
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		// Bids are rejected once the auction is settled
+		assert_noop!(
+			RmrkMarket::bid(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 200 * UNITS),
+			Error::<Test>::UnknownAuction
+		);
+	});
+}
+
+#[test]
+fn auction_late_bid_extends_end() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint an NFT
+		assert_ok!(basic_mint(0));
+		// ALICE creates an auction ending at block 10
+		assert_ok!(RmrkMarket::create_auction(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			100 * UNITS,
+			10 * UNITS,
+			10,
+		));
+		// BOB bids 2 blocks before the end, which extends the auction by AuctionExtendPeriod
+		run_to_block(8);
+		assert_ok!(RmrkMarket::bid(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 100 * UNITS));
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::AuctionExtended {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			end: 13,
+		}));
+		assert_eq!(RmrkMarket::auctions_ending_at(10).len(), 0);
+		assert_eq!(RmrkMarket::auctions_ending_at(13).len(), 1);
+		// Auction is not settled at its original end
+		run_to_block(12);
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(ALICE));
+		// Auction is settled at its extended end
+		run_to_block(13);
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+	});
+}
+
+#[test]
+fn auction_late_bid_extends_past_full_block() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs
+		for id in 0..3 {
+			assert_ok!(basic_mint(id));
+		}
+		// ALICE creates an auction ending at block 10
+		assert_ok!(RmrkMarket::create_auction(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			100 * UNITS,
+			10 * UNITS,
+			10,
+		));
+		// MaxAuctionsEndingPerBlock auctions already end at block 13
+		for id in 1..3 {
+			assert_ok!(RmrkMarket::create_auction(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				id,
+				100 * UNITS,
+				10 * UNITS,
+				13,
+			));
+		}
+		// BOB bids 2 blocks before the end, the auction is extended to the next block with room
+		run_to_block(8);
+		assert_ok!(RmrkMarket::bid(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 100 * UNITS));
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::AuctionExtended {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			end: 14,
+		}));
+		assert_eq!(RmrkMarket::auctions(COLLECTION_ID_0, NFT_ID_0).unwrap().end, 14);
+		assert_eq!(RmrkMarket::auctions_ending_at(10).len(), 0);
+		assert_eq!(RmrkMarket::auctions_ending_at(13).len(), 2);
+		assert_eq!(RmrkMarket::auctions_ending_at(14).len(), 1);
+		// Auction is settled at its extended end
+		run_to_block(13);
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(ALICE));
+		run_to_block(14);
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+	});
+}
+
+#[test]
+fn auction_without_bids_works() {
+	new_test_ext().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint two NFTs
+		assert_ok!(basic_mint(0));
+		assert_ok!(basic_mint(1));
+		// ALICE creates an auction for each NFT
+		assert_ok!(RmrkMarket::create_auction(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			100 * UNITS,
+			10 * UNITS,
+			10,
+		));
+		assert_ok!(RmrkMarket::create_auction(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			100 * UNITS,
+			10 * UNITS,
+			10,
+		));
+		// BOB cannot cancel ALICE's auction
+		assert_noop!(
+			RmrkMarket::cancel_auction(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::NoPermission
+		);
+		// ALICE cancels the first auction and the NFT is unlocked
+		assert_ok!(RmrkMarket::cancel_auction(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::AuctionCancelled {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		// The second auction ends without bids and the NFT is unlocked
+		run_to_block(10);
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::AuctionEndedWithoutBids {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_1,
+		}));
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_1)));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_1), Some(ALICE));
+	});
+}
+
+#[test]
+fn buy_with_asset_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(mint_to(ALICE, NFT_ID_0));
+		create_asset();
+		let asset = PaymentAsset::Asset(ASSET_ID_0);
+		// ALICE lists the NFT for 100 of the asset
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			100u128,
+			asset,
+			None,
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenListed {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			price: 100u128,
+			asset,
+		}));
+		// BOB buys the NFT, paying in the asset
+		assert_ok!(RmrkMarket::buy(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, Some(100u128)));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenSold {
+			owner: ALICE,
+			buyer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			price: 100u128,
+			asset,
+			fee: 2u128,
+			royalty: None,
+		}));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		// The price is paid in the asset, the native balances are untouched
+		assert_eq!(Assets::balance(ASSET_ID_0, BOB), 900);
+		assert_eq!(Assets::balance(ASSET_ID_0, ALICE), 98);
+		assert_eq!(Assets::balance(ASSET_ID_0, FEE_RECIPIENT), 2);
+		assert_eq!(Balances::free_balance(BOB), 200_000 * UNITS);
+	});
+}
+
+#[test]
+fn offer_with_asset_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(mint_to(ALICE, NFT_ID_0));
+		create_asset();
+		let asset = PaymentAsset::Asset(ASSET_ID_0);
+		// Offers below the minimum balance of the asset are rejected
+		assert_noop!(
+			RmrkMarket::make_offer(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 0, asset, None),
+			Error::<Test>::OfferTooLow
+		);
+		// BOB and CHARLIE make offers, the amounts are held by the pallet account
+		assert_ok!(RmrkMarket::make_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			100,
+			asset,
+			None,
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::OfferPlaced {
+			offerer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			price: 100,
+			asset,
+		}));
+		assert_ok!(RmrkMarket::make_offer(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			50,
+			asset,
+			None,
+		));
+		assert_eq!(Assets::balance(ASSET_ID_0, BOB), 900);
+		assert_eq!(Assets::balance(ASSET_ID_0, CHARLIE), 950);
+		assert_eq!(Assets::balance(ASSET_ID_0, RmrkMarket::account_id()), 150);
+		// CHARLIE withdraws their offer and gets the amount back
+		assert_ok!(RmrkMarket::withdraw_offer(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0));
+		assert_eq!(Assets::balance(ASSET_ID_0, CHARLIE), 1_000);
+		assert_eq!(Assets::balance(ASSET_ID_0, RmrkMarket::account_id()), 100);
+		// ALICE accepts the offer of BOB
+		assert_ok!(RmrkMarket::accept_offer(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, BOB));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		assert_eq!(Assets::balance(ASSET_ID_0, BOB), 900);
+		assert_eq!(Assets::balance(ASSET_ID_0, ALICE), 98);
+		assert_eq!(Assets::balance(ASSET_ID_0, FEE_RECIPIENT), 2);
+		assert_eq!(Assets::balance(ASSET_ID_0, RmrkMarket::account_id()), 0);
+	});
+}
+
+#[test]
+fn list_reserved_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(mint_to(ALICE, NFT_ID_0));
+		assert_ok!(mint_to(ALICE, NFT_ID_1));
+		// ALICE cannot reserve a listing for themselves
+		assert_noop!(
+			RmrkMarket::list_reserved(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				10u128,
+				NATIVE,
+				ALICE,
+				None,
+				None,
+			),
+			Error::<Test>::CannotReserveForOwner
+		);
+		// ALICE lists NFT [0,0] for BOB until block 5 and NFT [0,1] for BOB without end
+		assert_ok!(RmrkMarket::list_reserved(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			10u128,
+			NATIVE,
+			BOB,
+			Some(5),
+			None,
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::ListingReserved {
+			owner: ALICE,
+			buyer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			expires: Some(5),
+		}));
+		assert_ok!(RmrkMarket::list_reserved(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			20u128,
+			NATIVE,
+			BOB,
+			None,
+			None,
+		));
+		let mut reserved = RmrkMarket::reserved_listings(&BOB);
+		reserved.sort();
+		assert_eq!(
+			reserved,
+			vec![(COLLECTION_ID_0, NFT_ID_0, 10u128), (COLLECTION_ID_0, NFT_ID_1, 20u128)]
+		);
+		assert_eq!(RmrkMarket::reserved_listings(&CHARLIE), vec![]);
+		// CHARLIE cannot buy the NFTs while they are reserved for BOB
+		assert_noop!(
+			RmrkMarket::buy(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0, Some(10u128)),
+			Error::<Test>::ListingReserved
+		);
+		// BOB buys NFT [0,1]
+		assert_ok!(RmrkMarket::buy(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_1, Some(20u128)));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_1), Some(BOB));
+		assert_eq!(RmrkMarket::reserved_listings_of(&BOB, (COLLECTION_ID_0, NFT_ID_1)), None);
+		// Once the reservation ends, anyone can buy NFT [0,0]
+		run_to_block(5);
+		assert_eq!(RmrkMarket::reserved_listings(&BOB), vec![]);
+		assert_ok!(RmrkMarket::buy(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			Some(10u128),
+		));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(CHARLIE));
+		assert_eq!(RmrkMarket::reserved_listings_of(&BOB, (COLLECTION_ID_0, NFT_ID_0)), None);
+	});
+}
+
+#[test]
+fn update_offer_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(mint_to(ALICE, NFT_ID_0));
+		// BOB makes an offer on NFT [0,0]
+		assert_ok!(RmrkMarket::make_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			100 * UNITS,
+			NATIVE,
+			None,
+		));
+		assert_eq!(Balances::reserved_balance(BOB), 100 * UNITS);
+		// CHARLIE cannot update an offer they did not make
+		assert_noop!(
+			RmrkMarket::update_offer(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0, UNITS),
+			Error::<Test>::UnknownOffer
+		);
+		// BOB raises their offer, the difference is reserved
+		assert_ok!(RmrkMarket::update_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			150 * UNITS,
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::OfferUpdated {
+			offerer: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			price: 150 * UNITS,
+			asset: NATIVE,
+		}));
+		assert_eq!(Balances::reserved_balance(BOB), 150 * UNITS);
+		// BOB lowers their offer, the difference is unreserved
+		assert_ok!(RmrkMarket::update_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			60 * UNITS,
+		));
+		assert_eq!(Balances::reserved_balance(BOB), 60 * UNITS);
+		// The offer cannot be lowered below the minimum offer amount
+		assert_noop!(
+			RmrkMarket::update_offer(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 10 * UNITS),
+			Error::<Test>::OfferTooLow
+		);
+		// ALICE accepts the updated offer
+		assert_ok!(RmrkMarket::accept_offer(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, BOB));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 200_000 * UNITS - 60 * UNITS);
+	});
+}
+
+#[test]
+fn sale_history_and_stats_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(mint_to(ALICE, NFT_ID_0));
+		// NFT [0,0] is sold four times, one sale per block
+		let sales = [(ALICE, BOB, 10), (BOB, CHARLIE, 20), (CHARLIE, ALICE, 30), (ALICE, BOB, 40)];
+		for (block, (seller, buyer, price)) in sales.iter().enumerate() {
+			run_to_block(block as u64 + 1);
+			assert_ok!(RmrkMarket::list(
+				Origin::signed(seller.clone()),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				price * UNITS,
+				NATIVE,
+				None,
+			));
+			assert_ok!(RmrkMarket::buy(
+				Origin::signed(buyer.clone()),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				None,
+			));
+		}
+		let sale = |seller, buyer, price: u128, block| SaleRecord {
+			seller,
+			buyer,
+			nft_id: NFT_ID_0,
+			price: price * UNITS,
+			asset: NATIVE,
+			block,
+		};
+		// Only the three most recent sales are kept, from the oldest to the most recent
+		let expected =
+			vec![sale(BOB, CHARLIE, 20, 2), sale(CHARLIE, ALICE, 30, 3), sale(ALICE, BOB, 40, 4)];
+		assert_eq!(RmrkMarket::nft_sales(COLLECTION_ID_0, NFT_ID_0).into_inner(), expected);
+		assert_eq!(RmrkMarket::collection_sales(COLLECTION_ID_0).into_inner(), expected);
+		assert!(RmrkMarket::nft_sales(COLLECTION_ID_0, NFT_ID_1).is_empty());
+		// The statistics count all the sales
+		assert_eq!(
+			RmrkMarket::collection_stats(COLLECTION_ID_0),
+			CollectionStats { sales: 4, volume: 100 * UNITS, last_sale: Some((40 * UNITS, 4)) }
+		);
+		// No NFT is listed
+		assert_eq!(RmrkMarket::collection_floor_price(COLLECTION_ID_0), None);
+		// BOB lists NFT [0,0] and ALICE lists NFT [0,1]
+		assert_ok!(mint_to(ALICE, NFT_ID_1));
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			70 * UNITS,
+			NATIVE,
+			None,
+		));
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			50 * UNITS,
+			NATIVE,
+			None,
+		));
+		assert_eq!(RmrkMarket::collection_floor_price(COLLECTION_ID_0), Some(50 * UNITS));
+		// A listing reserved for CHARLIE is not part of the floor price
+		assert_ok!(mint_to(ALICE, 2));
+		assert_ok!(RmrkMarket::list_reserved(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			2,
+			5 * UNITS,
+			NATIVE,
+			CHARLIE,
+			None,
+			None,
+		));
+		assert_eq!(RmrkMarket::collection_floor_price(COLLECTION_ID_0), Some(50 * UNITS));
+		// Once ALICE unlists NFT [0,1], the floor price is the price of NFT [0,0]
+		assert_ok!(RmrkMarket::unlist(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_1));
+		assert_eq!(RmrkMarket::collection_floor_price(COLLECTION_ID_0), Some(70 * UNITS));
+	});
+}
+
+#[test]
+fn force_unlist_and_withdraw_offer_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(mint_to(ALICE, NFT_ID_0));
+		// ALICE lists NFT [0,0] and BOB makes an offer on it
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			10 * UNITS,
+			NATIVE,
+			None,
+		));
+		assert_ok!(RmrkMarket::make_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			100 * UNITS,
+			NATIVE,
+			None,
+		));
+		// Only the protocol origin can force unlist and withdraw offers
+		assert_noop!(
+			RmrkMarket::force_unlist(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RmrkMarket::force_withdraw_offer(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, BOB),
+			DispatchError::BadOrigin
+		);
+		// The protocol unlists NFT [0,0], which is unlocked
+		assert_ok!(RmrkMarket::force_unlist(Origin::root(), COLLECTION_ID_0, NFT_ID_0));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::TokenUnlisted {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert_eq!(RmrkMarket::listed_nfts(COLLECTION_ID_0, NFT_ID_0), None);
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert_noop!(
+			RmrkMarket::force_unlist(Origin::root(), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::CannotUnlistToken
+		);
+		// The protocol withdraws the offer of BOB, which is unreserved
+		assert_ok!(RmrkMarket::force_withdraw_offer(
+			Origin::root(),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BOB
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::OfferWithdrawn {
+			sender: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_noop!(
+			RmrkMarket::force_withdraw_offer(Origin::root(), COLLECTION_ID_0, NFT_ID_0, BOB),
+			Error::<Test>::UnknownOffer
+		);
+	});
+}
+
+#[test]
+fn force_cancel_all_for_collection_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		for nft_id in 0..4 {
+			assert_ok!(mint_to(ALICE, nft_id));
+		}
+		assert_ok!(mint_to(BOB, 4));
+		// ALICE lists NFT [0,0], lists NFT [0,1] in a bundle, proposes to swap NFT [0,2] for
+		// NFT [0,4] and auctions NFT [0,3]
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			10 * UNITS,
+			NATIVE,
+			None,
+		));
+		assert_ok!(RmrkMarket::list_bundle(
+			Origin::signed(ALICE),
+			bvec![(COLLECTION_ID_0, NFT_ID_1)],
+			10 * UNITS,
+			None,
+		));
+		assert_ok!(RmrkMarket::propose_swap(
+			Origin::signed(ALICE),
+			bvec![(COLLECTION_ID_0, 2)],
+			0,
+			SwapTarget::Nft(COLLECTION_ID_0, 4)
+		));
+		assert_ok!(RmrkMarket::create_auction(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			3,
+			10 * UNITS,
+			UNITS,
+			10,
+		));
+		// BOB makes an offer on NFT [0,0], CHARLIE bids in the auction and makes a collection
+		// offer
+		assert_ok!(RmrkMarket::make_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			100 * UNITS,
+			NATIVE,
+			None,
+		));
+		assert_ok!(RmrkMarket::bid(Origin::signed(CHARLIE), COLLECTION_ID_0, 3, 60 * UNITS));
+		assert_ok!(RmrkMarket::make_collection_offer(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			50 * UNITS,
+			2,
+			None,
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 3 * LISTING_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(BOB), 100 * UNITS);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 160 * UNITS);
+		// Only the protocol origin can cancel everything
+		assert_noop!(
+			RmrkMarket::force_cancel_all_for_collection(Origin::signed(ALICE), COLLECTION_ID_0, 10),
+			DispatchError::BadOrigin
+		);
+		// The listing, the offer and the collection offer are cancelled first
+		assert_ok!(RmrkMarket::force_cancel_all_for_collection(Origin::root(), COLLECTION_ID_0, 3));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::CollectionOfferWithdrawn {
+			sender: CHARLIE,
+			collection_id: COLLECTION_ID_0,
+		}));
+		assert_eq!(RmrkMarket::listed_nfts(COLLECTION_ID_0, NFT_ID_0), None);
+		assert_eq!(RmrkMarket::offers_in_collection(COLLECTION_ID_0, (NFT_ID_0, BOB)), None);
+		assert!(RmrkMarket::bundles(0).is_some());
+		assert_eq!(Balances::reserved_balance(CHARLIE), 60 * UNITS);
+		// The next call cancels the rest
+		assert_ok!(RmrkMarket::force_cancel_all_for_collection(
+			Origin::root(),
+			COLLECTION_ID_0,
+			10
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::CollectionCancelled {
+			collection_id: COLLECTION_ID_0,
+		}));
+		// All NFTs are unlocked and all amounts unreserved
+		for nft_id in 0..5 {
+			assert!(!RmrkCore::lock((COLLECTION_ID_0, nft_id)));
+		}
+		assert_eq!(RmrkMarket::listed_nfts(COLLECTION_ID_0, NFT_ID_0), None);
+		assert_eq!(RmrkMarket::offers((COLLECTION_ID_0, NFT_ID_0), BOB), None);
+		assert_eq!(RmrkMarket::collection_offers(COLLECTION_ID_0, CHARLIE), None);
+		assert_eq!(RmrkMarket::bundles(0), None);
+		assert_eq!(RmrkMarket::swaps(0), None);
+		assert_eq!(RmrkMarket::swaps_in_collection(COLLECTION_ID_0, 0), None);
+		assert_eq!(RmrkMarket::auctions(COLLECTION_ID_0, 3), None);
+		assert!(RmrkMarket::auctions_ending_at(10).is_empty());
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+	});
+}
+
+#[test]
+fn pause_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(mint_to(ALICE, NFT_ID_0));
+		// Only the protocol origin can pause the marketplace
+		assert_noop!(
+			RmrkMarket::set_market_paused(Origin::signed(ALICE), true),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(RmrkMarket::set_market_paused(Origin::root(), true));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::MarketPauseChanged {
+			paused: true,
+		}));
+		// Nothing can be listed or offered while the marketplace is paused
+		assert_noop!(
+			RmrkMarket::list(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				10 * UNITS,
+				NATIVE,
+				None
+			),
+			Error::<Test>::MarketPaused
+		);
+		assert_noop!(
+			RmrkMarket::make_offer(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				100 * UNITS,
+				NATIVE,
+				None
+			),
+			Error::<Test>::MarketPaused
+		);
+		assert_ok!(RmrkMarket::set_market_paused(Origin::root(), false));
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			10 * UNITS,
+			NATIVE,
+			None,
+		));
+		// The collection is paused, the listed NFT cannot be bought but can be unlisted
+		assert_ok!(RmrkMarket::set_collection_paused(Origin::root(), COLLECTION_ID_0, true));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::CollectionPauseChanged {
+			collection_id: COLLECTION_ID_0,
+			paused: true,
+		}));
+		assert_noop!(
+			RmrkMarket::buy(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, None),
+			Error::<Test>::CollectionPaused
+		);
+		assert_ok!(RmrkMarket::unlist(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		assert_noop!(
+			RmrkMarket::list(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				10 * UNITS,
+				NATIVE,
+				None
+			),
+			Error::<Test>::CollectionPaused
+		);
+		// Once the collection is unpaused, the NFT can be listed and bought again
+		assert_ok!(RmrkMarket::set_collection_paused(Origin::root(), COLLECTION_ID_0, false));
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			10 * UNITS,
+			NATIVE,
+			None,
+		));
+		assert_ok!(RmrkMarket::buy(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, None));
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+	});
+}
+
+#[test]
+fn auction_settles_while_paused() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(mint_to(ALICE, NFT_ID_0));
+		assert_ok!(RmrkMarket::create_auction(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			10 * UNITS,
+			UNITS,
+			10,
+		));
+		assert_ok!(RmrkMarket::bid(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 10 * UNITS));
+		// The collection is paused before the auction ends, BOB cannot be outbid
+		assert_ok!(RmrkMarket::set_collection_paused(Origin::root(), COLLECTION_ID_0, true));
+		assert_noop!(
+			RmrkMarket::bid(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0, 20 * UNITS),
+			Error::<Test>::CollectionPaused
+		);
+		// The auction is still settled at its end block
+		run_to_block(10);
+		assert_eq!(Uniques::owner(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert!(RmrkMarket::auctions(COLLECTION_ID_0, NFT_ID_0).is_none());
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		// Once the collection is unpaused, BOB can list the NFT
+		assert_ok!(RmrkMarket::set_collection_paused(Origin::root(), COLLECTION_ID_0, false));
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			10 * UNITS,
+			NATIVE,
+			None,
+		));
+	});
+}
+
+#[test]
+fn listing_deposit_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		for nft_id in 0..3 {
+			assert_ok!(mint_to(ALICE, nft_id));
+		}
+		let list = |nft_id, expires| {
+			RmrkMarket::list(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				nft_id,
+				10 * UNITS,
+				NATIVE,
+				expires,
+			)
+		};
+		// The deposit is reserved once per listing, and returned on unlist
+		assert_ok!(list(NFT_ID_0, None));
+		assert_eq!(Balances::reserved_balance(ALICE), LISTING_DEPOSIT);
+		assert_ok!(list(NFT_ID_0, None));
+		assert_eq!(Balances::reserved_balance(ALICE), LISTING_DEPOSIT);
+		assert_ok!(RmrkMarket::unlist(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		// The deposit is returned when the listing expires and is cleaned up
+		assert_ok!(list(NFT_ID_0, Some(3)));
+		run_to_block(4);
+		assert_eq!(RmrkMarket::listed_nfts(COLLECTION_ID_0, NFT_ID_0), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		// The deposit is returned when the NFT is sold, either listed or through an offer
+		assert_ok!(list(NFT_ID_1, None));
+		assert_ok!(RmrkMarket::make_offer(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_1,
+			100 * UNITS,
+			NATIVE,
+			None,
+		));
+		assert_ok!(RmrkMarket::accept_offer(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_1, BOB));
+		assert_eq!(RmrkMarket::listed_nfts(COLLECTION_ID_0, NFT_ID_1), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_ok!(list(2, None));
+		assert_ok!(RmrkMarket::buy(Origin::signed(BOB), COLLECTION_ID_0, 2, None));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		// Only the protocol origin can slash a fraudulent listing
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(list(NFT_ID_0, None));
+		assert_noop!(
+			RmrkMarket::slash_listing(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(RmrkMarket::slash_listing(Origin::root(), COLLECTION_ID_0, NFT_ID_0));
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::ListingDepositSlashed {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			amount: LISTING_DEPOSIT / 2,
+		}));
+		// Half of the deposit is slashed and the rest returned, the NFT is unlisted
+		assert_eq!(RmrkMarket::listed_nfts(COLLECTION_ID_0, NFT_ID_0), None);
+		assert!(!RmrkCore::lock((COLLECTION_ID_0, NFT_ID_0)));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), alice_balance - LISTING_DEPOSIT / 2);
+		assert_noop!(
+			RmrkMarket::slash_listing(Origin::root(), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::CannotUnlistToken
+		);
+	});
+}
+
+#[test]
+fn rental_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(basic_collection());
+		assert_ok!(mint_to(ALICE, NFT_ID_0));
+		let rent = |renter, duration, max_price_per_block| {
+			RmrkMarket::rent(
+				Origin::signed(renter),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				duration,
+				max_price_per_block,
+			)
+		};
+		// Only the owner can list a NFT for rent, for a non zero duration
+		assert_noop!(
+			RmrkMarket::list_rental(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, UNITS, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			RmrkMarket::list_rental(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, UNITS, 0),
+			Error::<Test>::InvalidRentalDuration
+		);
+		assert_noop!(rent(BOB, 5, None), Error::<Test>::UnknownRentalListing);
+		assert_ok!(RmrkMarket::list_rental(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			UNITS,
+			10
+		));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::RentalListed {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			price_per_block: UNITS,
+			max_duration: 10,
+		}));
+		assert_eq!(Balances::reserved_balance(ALICE), LISTING_DEPOSIT);
+		// Listing again replaces the listing and its deposit
+		assert_ok!(RmrkMarket::list_rental(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			UNITS,
+			10
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), LISTING_DEPOSIT);
+		// Renting checks the renter, the duration and the price per block
+		assert_noop!(rent(ALICE, 5, None), Error::<Test>::CannotRentOwnToken);
+		assert_noop!(rent(BOB, 0, None), Error::<Test>::InvalidRentalDuration);
+		assert_noop!(rent(BOB, 11, None), Error::<Test>::InvalidRentalDuration);
+		assert_noop!(rent(BOB, 5, Some(UNITS / 2)), Error::<Test>::PriceAboveMaximum);
+		// BOB rents the NFT for 5 blocks, paying the price per block for each of them
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(rent(BOB, 5, Some(UNITS)));
+		let fee = Permill::from_percent(2) * (5 * UNITS);
+		System::assert_has_event(MockEvent::RmrkMarket(crate::Event::TokenRented {
+			owner: ALICE,
+			renter: BOB,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			price: 5 * UNITS,
+			fee,
+			royalty: None,
+			expires: 6,
+		}));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 5 * UNITS - fee);
+		assert_eq!(Balances::free_balance(FEE_RECIPIENT), fee);
+		assert_eq!(RmrkCore::user_of(COLLECTION_ID_0, NFT_ID_0), Some(BOB));
+		// The NFT cannot be rented again while the rental is running
+		assert_noop!(rent(CHARLIE, 5, None), pallet_rmrk_core::Error::<Test>::UserStillActive);
+		// The NFT can be rented again once the rental ends
+		run_to_block(6);
+		assert_eq!(RmrkCore::user_of(COLLECTION_ID_0, NFT_ID_0), None);
+		assert_ok!(rent(CHARLIE, 2, None));
+		assert_eq!(RmrkCore::user_of(COLLECTION_ID_0, NFT_ID_0), Some(CHARLIE));
+		// The rental listing no longer applies once the NFT changes hands, and its new owner
+		// can unlist it
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+		));
+		run_to_block(8);
+		assert_noop!(rent(CHARLIE, 2, None), Error::<Test>::UnknownRentalListing);
+		assert_noop!(
+			RmrkMarket::unlist_rental(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(RmrkMarket::unlist_rental(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0));
+		System::assert_last_event(MockEvent::RmrkMarket(crate::Event::RentalUnlisted {
+			owner: ALICE,
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+		}));
+		assert!(RmrkMarket::rental_listings(COLLECTION_ID_0, NFT_ID_0).is_none());
+		// The deposit is returned to ALICE, who listed the NFT
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn migration_to_v1_works() {
+	new_test_ext().execute_with(|| {
+		use frame_support::{
+			storage::unhashed,
+			traits::{Get, GetStorageVersion, StorageVersion},
+		};
+
+		// A listing and an offer stored with the layout of storage version 0
+		StorageVersion::new(0).put::<RmrkMarket>();
+		unhashed::put(
+			&ListedNfts::<Test>::hashed_key_for(COLLECTION_ID_0, NFT_ID_0),
+			&migration::v0::ListInfo { listed_by: ALICE, amount: 10 * UNITS, expires: Some(5u64) },
+		);
+		unhashed::put(
+			&Offers::<Test>::hashed_key_for((COLLECTION_ID_0, NFT_ID_0), BOB),
+			&migration::v0::Offer { maker: BOB, amount: 100 * UNITS, expires: None::<u64> },
+		);
+
+		System::set_block_number(3);
+		migration::migrate_to_v1::<Test>();
+		assert_eq!(RmrkMarket::on_chain_storage_version(), 1);
+		// Expirations are cleaned up from the block of the upgrade on
+		assert_eq!(RmrkMarket::next_expiration_to_clean(), 3);
+		// The new fields are filled with their defaults
+		let list_info = RmrkMarket::listed_nfts(COLLECTION_ID_0, NFT_ID_0).unwrap();
+		assert_eq!(list_info.asset, NATIVE);
+		assert_eq!(list_info.kind, ListingKind::FixedPrice);
+		assert_eq!(list_info.reserved_for, None);
+		assert_eq!(list_info.reservation_expires, None);
+		assert_eq!(list_info.deposit, 0);
+		assert_eq!(RmrkMarket::offers((COLLECTION_ID_0, NFT_ID_0), BOB).unwrap().asset, NATIVE);
+		// The expiring listing and the offer are indexed
+		assert!(RmrkMarket::expirations(5, Expiring::Listing(COLLECTION_ID_0, NFT_ID_0)).is_some());
+		assert!(RmrkMarket::offers_in_collection(COLLECTION_ID_0, (NFT_ID_0, BOB)).is_some());
+		// The migration is not applied twice
+		assert_eq!(
+			migration::migrate_to_v1::<Test>(),
+			<Test as frame_system::Config>::DbWeight::get().reads(1)
+		);
+	});
+}
@@ -3,30 +3,203 @@
 // License: Apache 2.0 modified by RMRK, see LICENSE.md
 
 use frame_support::pallet_prelude::*;
+use rmrk_traits::primitives::{CollectionId, NftId};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
 use scale_info::TypeInfo;
 
+/// Id of a swap proposal
+pub type SwapId = u32;
+
+/// Id of a bundle listing
+pub type BundleId = u32;
+
 #[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct ListInfo<AccountId, Balance, BlockNumber> {
+pub enum PaymentAsset<AssetId> {
+	/// The native currency
+	Native,
+	/// An asset of the market `Assets`
+	Asset(AssetId),
+}
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ListInfo<AccountId, Balance, BlockNumber, AssetId> {
 	/// Owner who listed the NFT at the time
 	pub(super) listed_by: AccountId,
 	/// Listed amount
 	pub(super) amount: Balance,
+	/// Asset in which the listed amount is paid
+	pub(super) asset: PaymentAsset<AssetId>,
 	/// After this block the listing can't be bought
 	pub(super) expires: Option<BlockNumber>,
+	/// How the price of the listing is determined
+	pub(super) kind: ListingKind<Balance, BlockNumber>,
+	/// Only this account can buy the NFT while the reservation holds
+	pub(super) reserved_for: Option<AccountId>,
+	/// After this block the reservation ends and anyone can buy the NFT
+	pub(super) reservation_expires: Option<BlockNumber>,
+	/// Amount reserved from `listed_by` until the listing is removed
+	pub(super) deposit: Balance,
+}
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ListingKind<Balance, BlockNumber> {
+	/// The NFT is sold at the listed amount
+	FixedPrice,
+	/// The price of the NFT decreases linearly from the listed amount to `end_price` over
+	/// `duration` blocks from the `start` block
+	Dutch { end_price: Balance, start: BlockNumber, duration: BlockNumber },
 }
 
 #[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct Offer<AccountId, Balance, BlockNumber> {
+pub struct Offer<AccountId, Balance, BlockNumber, AssetId> {
 	/// User who made the offer
 	pub(super) maker: AccountId,
 	/// Offered amount
 	pub(super) amount: Balance,
+	/// Asset in which the offered amount is paid
+	pub(super) asset: PaymentAsset<AssetId>,
+	/// After this block the offer can't be accepted
+	pub(super) expires: Option<BlockNumber>,
+}
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CollectionOffer<AccountId, Balance, BlockNumber> {
+	/// User who made the offer
+	pub(super) maker: AccountId,
+	/// Offered amount for each NFT, in the native currency
+	pub(super) amount: Balance,
+	/// Number of NFTs of the collection the offer can still buy
+	pub(super) quantity: u32,
+	/// Amount still reserved from `maker` for the NFTs not bought yet
+	pub(super) reserved: Balance,
 	/// After this block the offer can't be accepted
 	pub(super) expires: Option<BlockNumber>,
 }
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AuctionInfo<AccountId, Balance, BlockNumber> {
+	/// Owner who created the auction
+	pub(super) seller: AccountId,
+	/// Bids below this amount are rejected, bids are in the native currency
+	pub(super) reserve_price: Balance,
+	/// Each bid must be at least this amount above the highest bid
+	pub(super) min_bid_increment: Balance,
+	/// Current highest bidder and their bid
+	pub(super) highest_bid: Option<(AccountId, Balance)>,
+	/// At this block the auction is settled
+	pub(super) end: BlockNumber,
+}
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Expiring<AccountId> {
+	/// Listing of a NFT
+	Listing(CollectionId, NftId),
+	/// Offer of an account on a NFT
+	Offer(CollectionId, NftId, AccountId),
+	/// Offer of an account on any NFT of a collection
+	CollectionOffer(CollectionId, AccountId),
+	/// Listing of a bundle of NFTs
+	Bundle(BundleId),
+}
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SwapTarget {
+	/// A specific NFT
+	Nft(CollectionId, NftId),
+	/// Any NFT of a collection
+	Collection(CollectionId),
+}
+
+impl SwapTarget {
+	/// Collection of the requested NFT
+	pub fn collection_id(&self) -> CollectionId {
+		match self {
+			SwapTarget::Nft(collection_id, _) | SwapTarget::Collection(collection_id) =>
+				*collection_id,
+		}
+	}
+}
+
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SwapInfo<AccountId, Balance, BoundedNfts> {
+	/// User who proposed the swap
+	pub(super) proposer: AccountId,
+	/// NFTs given by the proposer
+	pub(super) offered: BoundedNfts,
+	/// Amount given by the proposer in the native currency in addition to the NFTs
+	pub(super) top_up: Balance,
+	/// NFT requested in exchange
+	pub(super) target: SwapTarget,
+	/// Amount reserved from `proposer` until the swap is removed
+	pub(super) deposit: Balance,
+}
+
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BundleInfo<AccountId, Balance, BlockNumber, BoundedNfts> {
+	/// Owner who listed the NFTs at the time
+	pub(super) listed_by: AccountId,
+	/// NFTs sold together
+	pub(super) nfts: BoundedNfts,
+	/// Listed amount for all NFTs, in the native currency
+	pub(super) amount: Balance,
+	/// After this block the listing can't be bought
+	pub(super) expires: Option<BlockNumber>,
+	/// Amount reserved from `listed_by` until the bundle is removed
+	pub(super) deposit: Balance,
+}
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SaleRecord<AccountId, Balance, BlockNumber, AssetId> {
+	/// Owner who sold the NFT
+	pub(super) seller: AccountId,
+	/// User who bought the NFT
+	pub(super) buyer: AccountId,
+	/// Id of the NFT in its collection
+	pub(super) nft_id: NftId,
+	/// Price the NFT was sold at
+	pub(super) price: Balance,
+	/// Asset in which the price was paid
+	pub(super) asset: PaymentAsset<AssetId>,
+	/// Block at which the NFT was sold
+	pub(super) block: BlockNumber,
+}
+
+#[derive(
+	Encode, Decode, Eq, Copy, PartialEq, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CollectionStats<Balance, BlockNumber> {
+	/// Number of NFTs of the collection sold
+	pub(super) sales: u32,
+	/// Total price of the NFTs of the collection sold in the native currency
+	pub(super) volume: Balance,
+	/// Price and block of the last sale in the native currency
+	pub(super) last_sale: Option<(Balance, BlockNumber)>,
+}
+
+#[derive(Encode, Decode, Eq, Copy, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RentalInfo<AccountId, Balance, BlockNumber> {
+	/// Owner who listed the NFT for rent at the time
+	pub(super) listed_by: AccountId,
+	/// Price paid in the native currency for each block the NFT is rented for
+	pub(super) price_per_block: Balance,
+	/// Maximum number of blocks the NFT can be rented for at once
+	pub(super) max_duration: BlockNumber,
+	/// Amount reserved from `listed_by` until the NFT is unlisted
+	pub(super) deposit: Balance,
+}
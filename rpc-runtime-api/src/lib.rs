@@ -14,6 +14,13 @@ pub type PropertyKey = RpcString;
 pub type ThemeName = RpcString;
 
 sp_api::decl_runtime_apis! {
+	/// Versions of the API:
+	/// - 2: adds `nft_listing_price`
+	/// - 3: adds `reserved_listings`
+	/// - 4: adds `nft_sale_history`, `collection_sale_history`, `collection_stats` and
+	///   `collection_floor_price`
+	/// - 5: adds `collection_roles`
+	#[api_version(5)]
 	pub trait RmrkApi<
 		AccountId,
 		CollectionInfo,
@@ -22,10 +29,14 @@ sp_api::decl_runtime_apis! {
 		PropertyInfo,
 		BaseInfo,
 		PartType,
-		Theme
+		Theme,
+		Balance,
+		SaleRecord,
+		CollectionStats,
+		CollectionRole
 	>
 	where
-		AccountId: Encode,
+		AccountId: Encode + Decode,
 		CollectionInfo: Decode,
 		NftInfo: Decode,
 		ResourceInfo: Decode,
@@ -33,6 +44,10 @@ sp_api::decl_runtime_apis! {
 		BaseInfo: Decode,
 		PartType: Decode,
 		Theme: Decode,
+		Balance: Decode,
+		SaleRecord: Decode,
+		CollectionStats: Decode,
+		CollectionRole: Decode,
 	{
 		/// Get the latest created collection id
 		fn last_collection_idx() -> Result<CollectionId>;
@@ -72,5 +87,26 @@ sp_api::decl_runtime_apis! {
 
 		/// Get Theme info -- name, properties, and inherit flag
 		fn theme(base_id: BaseId, theme_name: ThemeName, filter_keys: Option<Vec<PropertyKey>>) -> Result<Option<Theme>>;
+
+		/// Get the current price of a listed NFT, which decreases every block for a Dutch auction
+		fn nft_listing_price(collection_id: CollectionId, nft_id: NftId) -> Result<Option<Balance>>;
+
+		/// Get the NFTs whose listing is reserved for an account, and their current price
+		fn reserved_listings(account_id: AccountId) -> Result<Vec<(CollectionId, NftId, Balance)>>;
+
+		/// Get the recent sales of a NFT, from the oldest to the most recent
+		fn nft_sale_history(collection_id: CollectionId, nft_id: NftId) -> Result<Vec<SaleRecord>>;
+
+		/// Get the recent sales of the NFTs of a collection, from the oldest to the most recent
+		fn collection_sale_history(collection_id: CollectionId) -> Result<Vec<SaleRecord>>;
+
+		/// Get the number of sales, volume and last sale of a collection
+		fn collection_stats(collection_id: CollectionId) -> Result<CollectionStats>;
+
+		/// Get the lowest price of the NFTs of a collection publicly listed in the native currency
+		fn collection_floor_price(collection_id: CollectionId) -> Result<Option<Balance>>;
+
+		/// Get the accounts holding a role on a collection, besides its issuer
+		fn collection_roles(collection_id: CollectionId) -> Result<Vec<(AccountId, CollectionRole)>>;
 	}
 }
@@ -37,7 +37,11 @@ macro_rules! pass_method {
 						PropertyInfo,
 						BaseInfo,
 						PartType,
-						Theme
+						Theme,
+						Balance,
+						SaleRecord,
+						CollectionStats,
+						CollectionRole
 					>
 				>(&at)
 			{
@@ -80,6 +84,10 @@ pub trait RmrkApi<
 	BaseInfo,
 	PartType,
 	Theme,
+	Balance,
+	SaleRecord,
+	CollectionStats,
+	CollectionRole,
 >
 {
 	#[method(name = "lastCollectionIdx")]
@@ -180,6 +188,64 @@ pub trait RmrkApi<
 		filter_keys: Option<Vec<String>>,
 		at: Option<BlockHash>,
 	) -> RpcResult<Option<Theme>>;
+
+	#[method(name = "nftListingPrice")]
+	/// Get the current price of a listed NFT, which decreases every block for a Dutch auction
+	fn nft_listing_price(
+		&self,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Balance>>;
+
+	#[method(name = "reservedListings")]
+	/// Get the NFTs whose listing is reserved for an account, and their current price
+	fn reserved_listings(
+		&self,
+		account_id: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(CollectionId, NftId, Balance)>>;
+
+	#[method(name = "nftSaleHistory")]
+	/// Get the recent sales of a NFT, from the oldest to the most recent
+	fn nft_sale_history(
+		&self,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<SaleRecord>>;
+
+	#[method(name = "collectionSaleHistory")]
+	/// Get the recent sales of the NFTs of a collection, from the oldest to the most recent
+	fn collection_sale_history(
+		&self,
+		collection_id: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<SaleRecord>>;
+
+	#[method(name = "collectionStats")]
+	/// Get the number of sales, volume and last sale of a collection
+	fn collection_stats(
+		&self,
+		collection_id: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<CollectionStats>;
+
+	#[method(name = "collectionFloorPrice")]
+	/// Get the lowest price of the NFTs of a collection publicly listed in the native currency
+	fn collection_floor_price(
+		&self,
+		collection_id: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Balance>>;
+
+	#[method(name = "collectionRoles")]
+	/// Get the accounts holding a role on a collection, besides its issuer
+	fn collection_roles(
+		&self,
+		collection_id: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, CollectionRole)>>;
 }
 
 #[async_trait]
@@ -194,6 +260,10 @@ impl<
 		BaseInfo,
 		PartType,
 		Theme,
+		Balance,
+		SaleRecord,
+		CollectionStats,
+		CollectionRole,
 	>
 	RmrkApiServer<
 		<Block as BlockT>::Hash,
@@ -205,6 +275,10 @@ impl<
 		BaseInfo,
 		PartType,
 		Theme,
+		Balance,
+		SaleRecord,
+		CollectionStats,
+		CollectionRole,
 	> for Rmrk<Block, C>
 where
 	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
@@ -218,8 +292,12 @@ where
 		BaseInfo,
 		PartType,
 		Theme,
+		Balance,
+		SaleRecord,
+		CollectionStats,
+		CollectionRole,
 	>,
-	AccountId: Encode,
+	AccountId: Encode + Decode,
 	CollectionInfo: Decode,
 	NftInfo: Decode,
 	ResourceInfo: Decode,
@@ -227,6 +305,10 @@ where
 	BaseInfo: Decode,
 	PartType: Decode,
 	Theme: Decode,
+	Balance: Decode,
+	SaleRecord: Decode,
+	CollectionStats: Decode,
+	CollectionRole: Decode,
 	Block: BlockT,
 {
 	pass_method!(last_collection_idx() -> CollectionId);
@@ -267,6 +349,13 @@ where
 			filter_keys: Option<Vec<String>>
 		) -> Option<Theme>
 	);
+	pass_method!(nft_listing_price(collection_id: CollectionId, nft_id: NftId) -> Option<Balance>);
+	pass_method!(reserved_listings(account_id: AccountId) -> Vec<(CollectionId, NftId, Balance)>);
+	pass_method!(nft_sale_history(collection_id: CollectionId, nft_id: NftId) -> Vec<SaleRecord>);
+	pass_method!(collection_sale_history(collection_id: CollectionId) -> Vec<SaleRecord>);
+	pass_method!(collection_stats(collection_id: CollectionId) -> CollectionStats);
+	pass_method!(collection_floor_price(collection_id: CollectionId) -> Option<Balance>);
+	pass_method!(collection_roles(collection_id: CollectionId) -> Vec<(AccountId, CollectionRole)>);
 }
 
 fn string_keys_to_bytes_keys(keys: Vec<String>) -> Vec<PropertyKey> {
@@ -10,7 +10,10 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
+	traits::{
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+		NumberFor, Verify,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, DispatchError, MultiSignature,
 };
@@ -30,7 +33,7 @@ pub use frame_support::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
 	},
-	BoundedVec, StorageValue,
+	BoundedVec, PalletId, StorageValue,
 };
 use frame_system::EnsureSigned;
 
@@ -43,8 +46,11 @@ use pallet_transaction_payment::CurrencyAdapter;
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
 
-use pallet_rmrk_core::{CollectionInfoOf, InstanceInfoOf, PropertyInfoOf, ResourceInfoOf};
+use pallet_rmrk_core::{
+	types::CollectionRole, CollectionInfoOf, InstanceInfoOf, PropertyInfoOf, ResourceInfoOf,
+};
 use pallet_rmrk_equip::{BaseInfoOf, BoundedThemeOf, PartTypeOf};
+use pallet_rmrk_market::{CollectionStatsOf, SaleRecordOf};
 use rmrk_traits::{primitives::*, NftChild};
 
 /// Import the template pallet.
@@ -130,7 +136,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -347,6 +353,10 @@ parameter_types! {
 	pub const MaxPriorities: u32 = 25;
 	pub const CollectionSymbolLimit: u32 = 100;
 	pub const MaxResourcesOnMint: u32 = 100;
+	// A batch of NFTs with `MaxResourcesOnMint` resources each must fit in a block
+	pub const MaxBatchMint: u32 = 25;
+	pub const MaxCollectionRoles: u32 = 100;
+	pub const MaxPropertyPermissions: u32 = 100;
 }
 
 impl pallet_rmrk_core::Config for Runtime {
@@ -358,17 +368,46 @@ impl pallet_rmrk_core::Config for Runtime {
 	type MaxPriorities = MaxPriorities;
 	type CollectionSymbolLimit = CollectionSymbolLimit;
 	type MaxResourcesOnMint = MaxResourcesOnMint;
+	type MaxBatchMint = MaxBatchMint;
+	type MaxCollectionRoles = MaxCollectionRoles;
+	type MaxPropertyPermissions = MaxPropertyPermissions;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
 }
 
 parameter_types! {
 	pub const MinimumOfferAmount: Balance = UNITS / 10_000;
+	pub const AuctionExtendPeriod: BlockNumber = 10 * MINUTES;
+	pub const MaxAuctionsEndingPerBlock: u32 = 50;
+	pub const MarketplaceFee: Permill = Permill::from_percent(2);
+	pub const MarketplacePalletId: PalletId = PalletId(*b"rmrk/mkt");
+	pub MarketplaceFeeRecipient: AccountId = MarketplacePalletId::get().into_account_truncating();
+	pub const ListingDeposit: Balance = 10 * CENTS;
+	pub const ListingDepositSlash: Permill = Permill::from_percent(50);
+	pub const MarketplaceEscrowPalletId: PalletId = PalletId(*b"rmrk/esc");
+	pub const MaxNftsPerSwap: u32 = 10;
+	pub const MaxNftsPerBundle: u32 = 10;
+	pub const MaxSaleHistory: u32 = 20;
 }
 
 impl pallet_rmrk_market::Config for Runtime {
 	type Event = Event;
 	type ProtocolOrigin = frame_system::EnsureRoot<AccountId>;
 	type Currency = Balances;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PalletId = MarketplaceEscrowPalletId;
 	type MinimumOfferAmount = MinimumOfferAmount;
+	type AuctionExtendPeriod = AuctionExtendPeriod;
+	type MaxAuctionsEndingPerBlock = MaxAuctionsEndingPerBlock;
+	type MarketplaceFee = MarketplaceFee;
+	type MarketplaceFeeRecipient = MarketplaceFeeRecipient;
+	type ListingDeposit = ListingDeposit;
+	type ListingDepositSlash = ListingDepositSlash;
+	type Slashed = ();
+	type MaxNftsPerSwap = MaxNftsPerSwap;
+	type MaxNftsPerBundle = MaxNftsPerBundle;
+	type MaxSaleHistory = MaxSaleHistory;
 }
 
 parameter_types! {
@@ -409,6 +448,32 @@ impl pallet_uniques::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * DOLLARS;
+	pub const AssetAccountDeposit: Balance = DOLLARS;
+	pub const AssetsStringLimit: u32 = 50;
+	pub const MetadataDepositBase: Balance = 10 * DOLLARS;
+	pub const MetadataDepositPerByte: Balance = DOLLARS;
+	pub const ApprovalDeposit: Balance = DOLLARS;
+}
+
+impl pallet_assets::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = AssetsStringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_utility::Config for Runtime {
 	type Event = Event;
 	type Call = Call;
@@ -438,6 +503,7 @@ construct_runtime!(
 		RmrkMarket: pallet_rmrk_market::{Pallet, Call, Storage, Event<T>},
 		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
 		Utility: pallet_utility::{Pallet, Call, Storage, Event},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -499,7 +565,11 @@ impl_runtime_apis! {
 		PropertyInfoOf<Runtime>,
 		BaseInfoOf<Runtime>,
 		PartTypeOf<Runtime>,
-		BoundedThemeOf<Runtime>
+		BoundedThemeOf<Runtime>,
+		Balance,
+		SaleRecordOf<Runtime>,
+		CollectionStatsOf<Runtime>,
+		CollectionRole
 	> for Runtime
 	{
 		fn last_collection_idx() -> pallet_rmrk_rpc_runtime_api::Result<CollectionId> {
@@ -592,6 +662,34 @@ impl_runtime_apis! {
 			let theme = RmrkEquip::get_theme(base_id, theme_name, filter_keys)?;
 			Ok(theme)
 		}
+
+		fn nft_listing_price(collection_id: CollectionId, nft_id: NftId) -> pallet_rmrk_rpc_runtime_api::Result<Option<Balance>> {
+			Ok(RmrkMarket::listing_price(collection_id, nft_id))
+		}
+
+		fn reserved_listings(account_id: AccountId) -> pallet_rmrk_rpc_runtime_api::Result<Vec<(CollectionId, NftId, Balance)>> {
+			Ok(RmrkMarket::reserved_listings(&account_id))
+		}
+
+		fn nft_sale_history(collection_id: CollectionId, nft_id: NftId) -> pallet_rmrk_rpc_runtime_api::Result<Vec<SaleRecordOf<Runtime>>> {
+			Ok(RmrkMarket::nft_sales(collection_id, nft_id).into_inner())
+		}
+
+		fn collection_sale_history(collection_id: CollectionId) -> pallet_rmrk_rpc_runtime_api::Result<Vec<SaleRecordOf<Runtime>>> {
+			Ok(RmrkMarket::collection_sales(collection_id).into_inner())
+		}
+
+		fn collection_stats(collection_id: CollectionId) -> pallet_rmrk_rpc_runtime_api::Result<CollectionStatsOf<Runtime>> {
+			Ok(RmrkMarket::collection_stats(collection_id))
+		}
+
+		fn collection_floor_price(collection_id: CollectionId) -> pallet_rmrk_rpc_runtime_api::Result<Option<Balance>> {
+			Ok(RmrkMarket::collection_floor_price(collection_id))
+		}
+
+		fn collection_roles(collection_id: CollectionId) -> pallet_rmrk_rpc_runtime_api::Result<Vec<(AccountId, CollectionRole)>> {
+			Ok(RmrkCore::collection_role_holders(collection_id))
+		}
 	}
 
 	impl sp_api::Core<Block> for Runtime {
@@ -139,6 +139,15 @@ declare module '@polkadot/api-base/types/submittable' {
       [key: string]: SubmittableExtrinsicFunction<ApiType>;
     };
     rmrkCore: {
+      /**
+       * Accept the nomination as new issuer of a collection, the roles granted by the
+       * previous issuer are revoked
+       * 
+       * Parameters:
+       * - `origin`: sender of the transaction, the nominated issuer
+       * - `collection_id`: collection id of the nft to become the issuer of
+       **/
+      acceptCollectionIssuer: AugmentedSubmittable<(collectionId: u32 | AnyNumber | Uint8Array) => SubmittableExtrinsic<ApiType>, [u32]>;
       /**
        * Accepts an NFT sent from another account to self or owned NFT
        * 
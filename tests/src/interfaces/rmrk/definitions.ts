@@ -95,6 +95,57 @@ export default {
                 {name: 'keys', type: 'Option<Vec<String>>'}
             ],
             'Option<RmrkTraitsTheme>'
+        ),
+        nftListingPrice: fn(
+            'Get the current price of a listed NFT, which decreases every block for a Dutch auction',
+            [
+                {name: 'collectionId', type: 'u32'},
+                {name: 'nftId', type: 'u32'}
+            ],
+            'Option<u128>'
+        ),
+        reservedListings: fn(
+            'Get the NFTs whose listing is reserved for an account, and their current price',
+            [
+                {name: 'accountId', type: 'AccountId32'}
+            ],
+            'Vec<(u32, u32, u128)>'
+        ),
+        nftSaleHistory: fn(
+            'Get the recent sales of a NFT, from the oldest to the most recent',
+            [
+                {name: 'collectionId', type: 'u32'},
+                {name: 'nftId', type: 'u32'}
+            ],
+            'Vec<PalletRmrkMarketSaleRecord>'
+        ),
+        collectionSaleHistory: fn(
+            'Get the recent sales of the NFTs of a collection, from the oldest to the most recent',
+            [
+                {name: 'collectionId', type: 'u32'}
+            ],
+            'Vec<PalletRmrkMarketSaleRecord>'
+        ),
+        collectionStats: fn(
+            'Get the number of sales, volume and last sale of a collection',
+            [
+                {name: 'collectionId', type: 'u32'}
+            ],
+            'PalletRmrkMarketCollectionStats'
+        ),
+        collectionFloorPrice: fn(
+            'Get the lowest price of the NFTs of a collection publicly listed in the native currency',
+            [
+                {name: 'collectionId', type: 'u32'}
+            ],
+            'Option<u128>'
+        ),
+        collectionRoles: fn(
+            'Get the accounts holding a role on a collection, besides its issuer',
+            [
+                {name: 'collectionId', type: 'u32'}
+            ],
+            'Vec<(AccountId32, PalletRmrkCoreCollectionRole)>'
         )
     }
 };
@@ -127,15 +127,22 @@ export async function changeIssuer(
   const alice = privateKey(issuerUri, Number(ss58Format));
   const bob = privateKey(newIssuer, Number(ss58Format));
 
-  let tx = api.tx.uniques.setAcceptOwnership(
-    api.createType("Option<u32>", collectionId)
+  let tx = api.tx.rmrkCore.changeCollectionIssuer(collectionId, bob.address);
+  let events = await executeTransaction(api, alice, tx);
+  const proposeIssuerResult = extractRmrkCoreTxResult(
+    events,
+    "IssuerChangeProposed",
+    (data) => {
+      return parseInt(data[2].toString(), 10);
+    }
   );
-  let events = await executeTransaction(api, bob, tx);
-  expect(isTxResultSuccess(events), "Error: Unable to accept ownership").to.be
-    .true;
+  expect(
+    proposeIssuerResult.success,
+    "Error: Unable to nominate NFT collection issuer"
+  ).to.be.true;
 
-  tx = api.tx.rmrkCore.changeCollectionIssuer(collectionId, bob.address);
-  events = await executeTransaction(api, alice, tx);
+  tx = api.tx.rmrkCore.acceptCollectionIssuer(collectionId);
+  events = await executeTransaction(api, bob, tx);
   const changeIssuerResult = extractRmrkCoreTxResult(
     events,
     "IssuerChanged",
@@ -78,7 +78,7 @@ pub trait Nft<AccountId, BoundedString, BoundedResourceVec> {
 	fn nft_mint(
 		sender: AccountId,
 		owner: AccountId,
-		nft_id: NftId,
+		nft_id: Option<NftId>,
 		collection_id: CollectionId,
 		royalty_recipient: Option<AccountId>,
 		royalty_amount: Option<Permill>,
@@ -89,7 +89,7 @@ pub trait Nft<AccountId, BoundedString, BoundedResourceVec> {
 	fn nft_mint_directly_to_nft(
 		sender: AccountId,
 		owner: (CollectionId, NftId),
-		nft_id: NftId,
+		nft_id: Option<NftId>,
 		collection_id: CollectionId,
 		royalty_recipient: Option<AccountId>,
 		royalty_amount: Option<Permill>,